@@ -0,0 +1,114 @@
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct AllocStats {
+    pub allocations: u64,
+    pub bytes: u64,
+    pub peak: u64,
+}
+
+pub const ENABLED: bool = cfg!(feature = "alloc-stats");
+
+// The counting allocator lives in the binary, it reports every allocation of
+// the current thread through `record_alloc` and `record_free`.
+#[cfg(feature = "alloc-stats")]
+mod counting {
+    use super::AllocStats;
+    use std::cell::Cell;
+
+    // Counters are kept per thread so that days solved in parallel do not
+    // show up in each other's numbers.
+    struct Counters {
+        allocations: Cell<u64>,
+        bytes: Cell<u64>,
+        live: Cell<i64>,
+        peak: Cell<i64>,
+    }
+
+    thread_local! {
+        static COUNTERS: Counters = const {
+            Counters {
+                allocations: Cell::new(0),
+                bytes: Cell::new(0),
+                live: Cell::new(0),
+                peak: Cell::new(0),
+            }
+        };
+    }
+
+    pub fn record_alloc(size: usize) {
+        let _ = COUNTERS.try_with(|c| {
+            c.allocations.set(c.allocations.get() + 1);
+            c.bytes.set(c.bytes.get() + size as u64);
+            c.live.set(c.live.get() + size as i64);
+            c.peak.set(c.peak.get().max(c.live.get()));
+        });
+    }
+
+    pub fn record_free(size: usize) {
+        let _ = COUNTERS.try_with(|c| c.live.set(c.live.get() - size as i64));
+    }
+
+    pub fn measure<R>(func: impl FnOnce() -> R) -> (R, AllocStats) {
+        let (allocations, bytes, live, peak) = COUNTERS.with(|c| {
+            let start = (
+                c.allocations.get(),
+                c.bytes.get(),
+                c.live.get(),
+                c.peak.get(),
+            );
+            c.peak.set(c.live.get());
+            start
+        });
+
+        let result = func();
+
+        let stats = COUNTERS.with(|c| {
+            let stats = AllocStats {
+                allocations: c.allocations.get() - allocations,
+                bytes: c.bytes.get() - bytes,
+                peak: (c.peak.get() - live).max(0) as u64,
+            };
+            c.peak.set(c.peak.get().max(peak));
+            stats
+        });
+        (result, stats)
+    }
+}
+
+#[cfg(feature = "alloc-stats")]
+pub use counting::{measure, record_alloc, record_free};
+
+#[cfg(not(feature = "alloc-stats"))]
+pub fn measure<R>(func: impl FnOnce() -> R) -> (R, AllocStats) {
+    (func(), AllocStats::default())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[cfg(feature = "alloc-stats")]
+    #[test]
+    fn test_measure() {
+        let (len, stats) = measure(|| {
+            record_alloc(1024);
+            record_alloc(4096);
+            record_free(4096);
+            1
+        });
+        assert_eq!(len, 1);
+        assert_eq!(stats.allocations, 2);
+        assert_eq!(stats.bytes, 1024 + 4096);
+        assert_eq!(stats.peak, 1024 + 4096);
+
+        let (_, stats) = measure(|| record_alloc(16));
+        assert_eq!(stats.peak, 16);
+    }
+
+    #[cfg(not(feature = "alloc-stats"))]
+    #[test]
+    fn test_measure() {
+        let (len, stats) = measure(|| vec![0_u8; 4096].len());
+        assert_eq!(len, 4096);
+        assert_eq!(stats, AllocStats::default());
+    }
+}
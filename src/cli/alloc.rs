@@ -0,0 +1,52 @@
+use advent_of_code::alloc::{record_alloc, record_free};
+use std::alloc::{GlobalAlloc, Layout, System};
+
+// Forwards to the system allocator and reports every allocation to the
+// counters behind `advent_of_code::alloc::measure`.
+struct CountingAllocator;
+
+unsafe impl GlobalAlloc for CountingAllocator {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        record_alloc(layout.size());
+        unsafe { System.alloc(layout) }
+    }
+
+    unsafe fn alloc_zeroed(&self, layout: Layout) -> *mut u8 {
+        record_alloc(layout.size());
+        unsafe { System.alloc_zeroed(layout) }
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        record_free(layout.size());
+        unsafe { System.dealloc(ptr, layout) }
+    }
+
+    unsafe fn realloc(&self, ptr: *mut u8, layout: Layout, new_size: usize) -> *mut u8 {
+        record_free(layout.size());
+        record_alloc(new_size);
+        unsafe { System.realloc(ptr, layout, new_size) }
+    }
+}
+
+#[global_allocator]
+static GLOBAL: CountingAllocator = CountingAllocator;
+
+#[cfg(test)]
+mod tests {
+    use advent_of_code::alloc::measure;
+
+    #[test]
+    fn test_measure() {
+        let (len, stats) = measure(|| {
+            let mut kept = Vec::with_capacity(1024);
+            kept.push(0_u8);
+            let temporary = vec![0_u8; 4096];
+            drop(temporary);
+            kept.len()
+        });
+        assert_eq!(len, 1);
+        assert_eq!(stats.allocations, 2);
+        assert_eq!(stats.bytes, 1024 + 4096);
+        assert_eq!(stats.peak, 1024 + 4096);
+    }
+}
@@ -0,0 +1,179 @@
+use advent_of_code::error::{Error, Result};
+use advent_of_code::years::{Answer, DayNum, Solution};
+use std::fmt;
+use std::fs;
+use std::io::ErrorKind;
+use toml::{Table, Value};
+
+pub const ANSWERS_PATH: &str = "answers.toml";
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Verdict {
+    Pass,
+    Fail,
+    New,
+}
+
+impl fmt::Display for Verdict {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Verdict::Pass => write!(f, "PASS"),
+            Verdict::Fail => write!(f, "FAIL"),
+            Verdict::New => write!(f, "NEW"),
+        }
+    }
+}
+
+pub struct Answers {
+    table: Table,
+}
+
+impl Answers {
+    pub fn load(path: &str) -> Result<Answers> {
+        let table = match fs::read_to_string(path) {
+            Ok(content) => content
+                .parse::<Table>()
+                .map_err(|e| Error::format(path, e))?,
+            Err(e) if e.kind() == ErrorKind::NotFound => Table::new(),
+            Err(e) => return Err(Error::io(path, e)),
+        };
+
+        Ok(Answers { table })
+    }
+
+    pub fn save(&self, path: &str) -> Result<()> {
+        fs::write(path, self.table.to_string()).map_err(|e| Error::io(path, e))
+    }
+
+    pub fn get(&self, year: i32, day: DayNum, part: usize) -> Option<String> {
+        let value = self
+            .table
+            .get(&year.to_string())?
+            .get(day.to_string())?
+            .get(format!("part{part}"))?;
+
+        match value {
+            Value::String(s) => Some(s.clone()),
+            value => Some(value.to_string()),
+        }
+    }
+
+    pub fn record(&mut self, year: i32, day: DayNum, solution: &Solution) -> Result<()> {
+        for (part, answer) in [(1, &solution.part1), (2, &solution.part2)] {
+            self.record_part(year, day, part, answer)?;
+        }
+        Ok(())
+    }
+
+    pub fn record_part(
+        &mut self,
+        year: i32,
+        day: DayNum,
+        part: usize,
+        answer: &Answer,
+    ) -> Result<()> {
+        let Some(value) = to_value(answer) else {
+            return Ok(());
+        };
+
+        let year_table = self
+            .table
+            .entry(year.to_string())
+            .or_insert_with(|| Value::Table(Table::new()));
+        let Value::Table(year_table) = year_table else {
+            return Err(Error::format(
+                ANSWERS_PATH,
+                format!("expected a table for year {year}"),
+            ));
+        };
+
+        let day_table = year_table
+            .entry(day.to_string())
+            .or_insert_with(|| Value::Table(Table::new()));
+        let Value::Table(day_table) = day_table else {
+            return Err(Error::format(
+                ANSWERS_PATH,
+                format!("expected a table for {year} day {day}"),
+            ));
+        };
+
+        day_table.insert(format!("part{part}"), value);
+        Ok(())
+    }
+
+    pub fn verify(&self, year: i32, day: DayNum, part: usize, answer: &Answer) -> Option<Verdict> {
+        if *answer == Answer::Unsolved {
+            return None;
+        }
+
+        match self.get(year, day, part) {
+            Some(expected) if expected == answer.to_string() => Some(Verdict::Pass),
+            Some(_) => Some(Verdict::Fail),
+            None => Some(Verdict::New),
+        }
+    }
+}
+
+fn to_value(answer: &Answer) -> Option<Value> {
+    match answer {
+        Answer::Number(n) => match i64::try_from(*n) {
+            Ok(n) => Some(Value::Integer(n)),
+            Err(_) => Some(Value::String(n.to_string())),
+        },
+        Answer::Text(s) => Some(Value::String(s.clone())),
+        Answer::Unsolved => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use advent_of_code::years::{Context, Solver};
+
+    #[test]
+    fn test_load() {
+        let dir = std::env::temp_dir().join(format!("aoc-answers-{}", std::process::id()));
+        fs::create_dir_all(&dir).unwrap();
+
+        let missing = dir.join("answers.toml");
+        assert!(Answers::load(missing.to_str().unwrap()).is_ok());
+
+        let error = Answers::load(dir.to_str().unwrap()).err().unwrap();
+        assert!(matches!(error, Error::Io { .. }));
+
+        fs::remove_dir_all(dir).unwrap();
+    }
+
+    #[test]
+    fn test_record_and_verify() {
+        let mut answers = Answers {
+            table: Table::new(),
+        };
+        let day = DayNum::new(17).unwrap();
+        let solution = Solver::new(&Context::default(), ())
+            .part1(|_| "4,6,3,5,6,3,5,2,1,0")
+            .part2(|_| 117440_u64)
+            .unwrap();
+
+        assert_eq!(
+            answers.verify(2024, day, 1, &solution.part1),
+            Some(Verdict::New)
+        );
+
+        answers.record(2024, day, &solution).unwrap();
+        let answers = Answers {
+            table: answers.table.to_string().parse().unwrap(),
+        };
+
+        assert_eq!(answers.get(2024, day, 2), Some("117440".to_string()));
+        assert_eq!(
+            answers.verify(2024, day, 1, &solution.part1),
+            Some(Verdict::Pass)
+        );
+        assert_eq!(
+            answers.verify(2024, day, 2, &Answer::Number(117441)),
+            Some(Verdict::Fail)
+        );
+        assert_eq!(answers.verify(2024, day, 2, &Answer::Unsolved), None);
+    }
+}
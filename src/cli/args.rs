@@ -0,0 +1,203 @@
+use super::answers::ANSWERS_PATH;
+use super::cache::{self, Cache};
+use super::input::{self, InputSource};
+use super::report::Format;
+use super::submit::GUESSES_PATH;
+use super::{client, pool, scaffold};
+use advent_of_code::error::{Error, Result};
+use advent_of_code::years::{self, Context, DayNum, Year, get_year};
+use std::path::PathBuf;
+use std::time::Duration;
+
+pub const COMMANDS: &[&str] = &[
+    "list", "verify", "record", "bench", "fetch", "submit", "new", "cache",
+];
+
+pub struct Options {
+    pub input: InputSource,
+    pub inputs_dir: Option<PathBuf>,
+    pub ctx: Context,
+    pub format: Format,
+    pub jobs: usize,
+    pub timeout: Option<Duration>,
+    pub cache: Option<Cache>,
+}
+
+impl Options {
+    pub fn parse(args: &mut Vec<String>) -> Result<Options> {
+        let input = match take_option(args, "--example")? {
+            Some(name) => InputSource::Example(name),
+            None => InputSource::from_arg(take_option(args, "--input")?),
+        };
+        if let InputSource::Example(_) = input
+            && args.iter().any(|arg| arg == "--input")
+        {
+            return Err(Error::usage("--input and --example cannot be combined"));
+        }
+        let inputs_dir = take_option(args, "--inputs-dir")?.map(PathBuf::from);
+        if inputs_dir.is_some() && input != InputSource::Default {
+            return Err(Error::usage(
+                "--inputs-dir cannot be combined with --input or --example",
+            ));
+        }
+        let ctx = Context {
+            part: parse_part(take_option(args, "--part")?)?,
+            params: take_params(args)?,
+            ..Context::default()
+        };
+        let format = Format::from_arg(take_option(args, "--format")?)?;
+        let jobs = parse_option(take_option(args, "--jobs")?, "--jobs")?
+            .unwrap_or_else(pool::default_jobs);
+        if jobs == 0 {
+            return Err(Error::usage("--jobs should be at least 1"));
+        }
+        let timeout = parse_option::<f64>(take_option(args, "--timeout")?, "--timeout")?
+            .map(|secs| {
+                Duration::try_from_secs_f64(secs)
+                    .ok()
+                    .filter(|timeout| !timeout.is_zero())
+                    .ok_or_else(|| {
+                        Error::usage(format!(
+                            "--timeout should be a positive number, got '{secs}'"
+                        ))
+                    })
+            })
+            .transpose()?;
+        let cache = if take_flag(args, "--no-cache") > 0 {
+            None
+        } else {
+            Cache::from_env()?
+        };
+        Ok(Options {
+            input,
+            inputs_dir,
+            ctx,
+            format,
+            jobs,
+            timeout,
+            cache,
+        })
+    }
+}
+
+pub fn help() {
+    println!(
+        "usage:
+ advent_of_code <num1> <num2>: Solves the year <num1> problem for day <num2>
+ advent_of_code <num1>: Solves all implemented days for year <num1>
+ advent_of_code all: Solves all implemented days for every year
+ advent_of_code list: Lists all implemented days
+ advent_of_code verify [<num1> [<num2>]]: Compares answers against {ANSWERS_PATH}
+ advent_of_code record <num1> [<num2>]: Records the current answers in {ANSWERS_PATH}
+ advent_of_code bench <num1> [<num2>] [--runs <n>] [--save <file>] [--compare <file>] [--threshold <percent>]:
+     Benchmarks parse, part1 and part2
+ advent_of_code fetch <num1> <num2>: Downloads the input of year <num1> day <num2> unless it is already cached
+ advent_of_code new <num1> <num2>: Generates the file of year <num1> day <num2> and registers it in {}/year_<num1>/mod.rs
+ advent_of_code submit <num1> <num2> <part>: Submits the answer of year <num1> day <num2> part <part>
+     and records it in {ANSWERS_PATH} if it is right, wrong answers are kept in {GUESSES_PATH}
+ advent_of_code cache clear: Removes every answer stored in the answer cache
+
+options:
+ --input <file>: Reads the input from <file> instead of the day's input file, '-' reads from stdin
+ --inputs-dir <dir>: Solves a single day for every file in <dir> and prints a table per file
+ --example <name>: Runs the day on its embedded example <name> and checks the expected answers
+ --part <1|2>: Only runs the given part
+ --param <key=value>: Overrides a puzzle parameter of the day, can be repeated
+ --format <text|json>: Prints the results of solved days as text (default) or JSON
+ --timeout <secs>: Reports TIMEOUT for a day that takes longer than <secs> seconds and moves on
+ --jobs <n>: Solves and verifies up to <n> days in parallel, defaults to the number of CPUs
+ --no-cache: Solves every day even if its answers are in the answer cache
+ -v, -vv: Prints the debug (-v) or debug and trace (-vv) output of the days on stderr
+ The {} environment variable overrides the base directory of the input files
+ Builds with the alloc-stats feature also report allocations and peak memory of every phase
+ Setting {} to a directory caches the answers of every solved day per input and build,
+ runs with --param or an example's parameters are never cached
+ Inputs are checked against the shape each day expects before solving, and a short hash of
+ every solved input is printed so answers on different machines can be compared
+
+fetch and submit read the session token from {} or 'session' in the config file ({} or {}),
+the server from {} or 'base_url' and an optional 'contact' for the User-Agent",
+        scaffold::YEARS_DIR,
+        input::INPUT_DIR_VAR,
+        cache::CACHE_DIR_VAR,
+        client::SESSION_VAR,
+        client::CONFIG_VAR,
+        client::config_path()
+            .map(|path| path.display().to_string())
+            .unwrap_or_default(),
+        client::BASE_URL_VAR
+    );
+}
+
+pub fn take_option(args: &mut Vec<String>, name: &str) -> Result<Option<String>> {
+    let Some(idx) = args.iter().position(|arg| arg == name) else {
+        return Ok(None);
+    };
+    args.remove(idx);
+    if idx < args.len() {
+        Ok(Some(args.remove(idx)))
+    } else {
+        Err(Error::usage(format!("{name} requires a value")))
+    }
+}
+
+pub fn parse_option<T: std::str::FromStr>(value: Option<String>, name: &str) -> Result<Option<T>> {
+    value
+        .map(|value| {
+            value
+                .parse()
+                .map_err(|_| Error::usage(format!("{name} should be a number, got '{value}'")))
+        })
+        .transpose()
+}
+
+pub fn take_flag(args: &mut Vec<String>, name: &str) -> usize {
+    let len = args.len();
+    args.retain(|arg| arg != name);
+    len - args.len()
+}
+
+fn take_params(args: &mut Vec<String>) -> Result<Vec<(String, String)>> {
+    let mut params = Vec::new();
+    while let Some(param) = take_option(args, "--param")? {
+        let (key, value) = param
+            .split_once('=')
+            .ok_or_else(|| Error::usage(format!("--param should be key=value, got '{param}'")))?;
+        params.push((key.to_string(), value.to_string()));
+    }
+
+    Ok(params)
+}
+
+fn parse_part(value: Option<String>) -> Result<Option<usize>> {
+    match value.as_deref() {
+        None => Ok(None),
+        Some("1") => Ok(Some(1)),
+        Some("2") => Ok(Some(2)),
+        Some(part) => Err(Error::usage(format!(
+            "--part should be 1 or 2, got '{part}'"
+        ))),
+    }
+}
+
+pub fn parse_year(arg: &str) -> Result<&'static dyn Year> {
+    let year = arg
+        .parse::<i32>()
+        .map_err(|_| Error::usage(format!("year should be a number, got '{arg}'")))?;
+    get_year(year).ok_or_else(|| {
+        Error::usage(if years::SOURCE_YEARS.contains(&year) {
+            format!("year {year} is not compiled in, rebuild with --features year{year}")
+        } else {
+            format!("year {year} is not implemented yet")
+        })
+    })
+}
+
+pub fn parse_day_num(arg: &str) -> Result<i32> {
+    arg.parse::<i32>()
+        .map_err(|_| Error::usage(format!("day should be a number, got '{arg}'")))
+}
+
+pub fn parse_day(year: &dyn Year, arg: &str) -> Result<DayNum> {
+    year.day_num(parse_day_num(arg)?)
+}
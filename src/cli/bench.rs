@@ -0,0 +1,173 @@
+use super::input::Input;
+use super::report::format_duration;
+use advent_of_code::error::{Error, Result};
+use advent_of_code::years::{AdventDay, Context, DayNum, Timings};
+use serde::{Deserialize, Serialize};
+use std::fs;
+use std::time::Duration;
+
+pub const DEFAULT_RUNS: usize = 10;
+pub const DEFAULT_THRESHOLD: f64 = 10.0;
+
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct Stats {
+    pub min_ns: u64,
+    pub median_ns: u64,
+    pub max_ns: u64,
+}
+
+impl Stats {
+    fn from_durations(mut durations: Vec<Duration>) -> Stats {
+        durations.sort();
+        let as_ns = |d: &Duration| d.as_nanos() as u64;
+
+        Stats {
+            min_ns: durations.first().map(as_ns).unwrap_or(0),
+            median_ns: durations.get(durations.len() / 2).map(as_ns).unwrap_or(0),
+            max_ns: durations.last().map(as_ns).unwrap_or(0),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct BenchResult {
+    pub year: i32,
+    pub day: i32,
+    pub runs: usize,
+    pub parse: Stats,
+    pub part1: Stats,
+    pub part2: Stats,
+}
+
+impl BenchResult {
+    fn phases(&self) -> [(&'static str, &Stats); 3] {
+        [
+            ("parse", &self.parse),
+            ("part1", &self.part1),
+            ("part2", &self.part2),
+        ]
+    }
+}
+
+pub fn bench_day(
+    year: i32,
+    day: DayNum,
+    advent_day: &dyn AdventDay,
+    input: &Input,
+    ctx: &Context,
+    runs: usize,
+) -> Result<BenchResult> {
+    let timings = (0..runs.max(1))
+        .map(|_| {
+            input
+                .solve(advent_day, ctx)
+                .map(|solution| solution.timings)
+        })
+        .collect::<Result<Vec<Timings>>>()?;
+
+    Ok(BenchResult {
+        year,
+        day: day.get(),
+        runs: timings.len(),
+        parse: Stats::from_durations(timings.iter().map(|t| t.parse).collect()),
+        part1: Stats::from_durations(timings.iter().map(|t| t.part1).collect()),
+        part2: Stats::from_durations(timings.iter().map(|t| t.part2).collect()),
+    })
+}
+
+pub fn save(path: &str, results: &[BenchResult]) -> Result<()> {
+    let json = serde_json::to_string_pretty(results).map_err(|e| Error::format(path, e))?;
+    fs::write(path, json).map_err(|e| Error::io(path, e))
+}
+
+pub fn load(path: &str) -> Result<Vec<BenchResult>> {
+    let json = fs::read_to_string(path).map_err(|e| Error::io(path, e))?;
+    serde_json::from_str(&json).map_err(|e| Error::format(path, e))
+}
+
+fn is_regression(current: &Stats, previous: &Stats, threshold: f64) -> bool {
+    current.median_ns as f64 > previous.median_ns as f64 * (1.0 + threshold / 100.0)
+}
+
+fn format_ns(ns: u64) -> String {
+    format_duration(Duration::from_nanos(ns))
+}
+
+pub fn print_result(result: &BenchResult, previous: Option<&BenchResult>, threshold: f64) -> bool {
+    println!("{} day {} ({} runs)", result.year, result.day, result.runs);
+
+    let mut regressed = false;
+    for (idx, (phase, stats)) in result.phases().into_iter().enumerate() {
+        let mut line = format!(
+            "  {phase}: min {:>10} | median {:>10} | max {:>10}",
+            format_ns(stats.min_ns),
+            format_ns(stats.median_ns),
+            format_ns(stats.max_ns)
+        );
+
+        if let Some(previous) = previous {
+            let (_, previous_stats) = previous.phases()[idx];
+            let change = if previous_stats.median_ns == 0 {
+                0.0
+            } else {
+                (stats.median_ns as f64 / previous_stats.median_ns as f64 - 1.0) * 100.0
+            };
+            line += &format!(
+                " | previous {:>10} ({change:+.1}%)",
+                format_ns(previous_stats.median_ns)
+            );
+
+            if is_regression(stats, previous_stats, threshold) {
+                line += " REGRESSION";
+                regressed = true;
+            }
+        }
+
+        println!("{line}");
+    }
+
+    regressed
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_stats() {
+        let stats = Stats::from_durations(vec![
+            Duration::from_nanos(30),
+            Duration::from_nanos(10),
+            Duration::from_nanos(20),
+            Duration::from_nanos(50),
+            Duration::from_nanos(40),
+        ]);
+        assert_eq!(
+            stats,
+            Stats {
+                min_ns: 10,
+                median_ns: 30,
+                max_ns: 50
+            }
+        );
+    }
+
+    #[test]
+    fn test_is_regression() {
+        let previous = Stats {
+            min_ns: 100,
+            median_ns: 100,
+            max_ns: 100,
+        };
+        let slower = Stats {
+            median_ns: 120,
+            ..previous
+        };
+        let similar = Stats {
+            median_ns: 105,
+            ..previous
+        };
+        assert!(is_regression(&slower, &previous, 10.0));
+        assert!(!is_regression(&similar, &previous, 10.0));
+    }
+}
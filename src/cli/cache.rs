@@ -0,0 +1,293 @@
+use advent_of_code::error::{Error, Result};
+use advent_of_code::library::text::InputHash;
+use advent_of_code::years::{Answer, DayNum, Memory, Solution, Timings};
+use serde::{Deserialize, Serialize};
+use std::env;
+use std::ffi::OsStr;
+use std::fs;
+use std::io::ErrorKind;
+use std::path::{Path, PathBuf};
+use std::time::UNIX_EPOCH;
+
+pub const CACHE_DIR_VAR: &str = "AOC_CACHE_DIR";
+
+#[derive(Debug, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+enum Entry {
+    Number(String),
+    Text(String),
+    Unsolved,
+}
+
+impl From<&Answer> for Entry {
+    fn from(answer: &Answer) -> Self {
+        match answer {
+            Answer::Number(n) => Entry::Number(n.to_string()),
+            Answer::Text(s) => Entry::Text(s.clone()),
+            Answer::Unsolved => Entry::Unsolved,
+        }
+    }
+}
+
+impl Entry {
+    fn into_answer(self) -> Option<Answer> {
+        match self {
+            Entry::Number(n) => n.parse().ok().map(Answer::Number),
+            Entry::Text(s) => Some(Answer::Text(s)),
+            Entry::Unsolved => Some(Answer::Unsolved),
+        }
+    }
+}
+
+pub struct Key {
+    pub year: i32,
+    pub day: DayNum,
+    pub input: InputHash,
+}
+
+fn parts(part: Option<usize>) -> Vec<usize> {
+    match part {
+        Some(part) => vec![part],
+        None => vec![1, 2],
+    }
+}
+
+// Answers of previous runs, stored as one file per part so days solved in
+// parallel never write to the same file.
+#[derive(Debug, Clone)]
+pub struct Cache {
+    dir: PathBuf,
+    build: u64,
+}
+
+impl Cache {
+    pub fn from_env() -> Result<Option<Cache>> {
+        env::var_os(CACHE_DIR_VAR)
+            .map(|dir| Cache::open(PathBuf::from(dir)))
+            .transpose()
+    }
+
+    fn open(dir: PathBuf) -> Result<Cache> {
+        Ok(Cache {
+            dir,
+            build: build_id()?,
+        })
+    }
+
+    fn path(&self, key: &Key, part: usize) -> PathBuf {
+        self.dir
+            .join(key.year.to_string())
+            .join(format!("day{}", key.day))
+            .join(format!("{}-{:016x}-part{part}.json", key.input, self.build))
+    }
+
+    fn get_part(&self, key: &Key, part: usize) -> Option<Answer> {
+        let json = fs::read_to_string(self.path(key, part)).ok()?;
+        serde_json::from_str::<Entry>(&json).ok()?.into_answer()
+    }
+
+    pub fn get(&self, key: &Key, part: Option<usize>) -> Option<Solution> {
+        let mut answers = [Answer::Unsolved, Answer::Unsolved];
+        for part in parts(part) {
+            answers[part - 1] = self.get_part(key, part)?;
+        }
+
+        let [part1, part2] = answers;
+        Some(Solution {
+            part1,
+            part2,
+            timings: Timings::default(),
+            memory: Memory::default(),
+            input_hash: Some(key.input),
+            cached: true,
+        })
+    }
+
+    pub fn put(&self, key: &Key, part: Option<usize>, solution: &Solution) -> Result<()> {
+        for part in parts(part) {
+            let answer = if part == 1 {
+                &solution.part1
+            } else {
+                &solution.part2
+            };
+            let path = self.path(key, part);
+            if let Some(dir) = path.parent() {
+                fs::create_dir_all(dir).map_err(|e| Error::io(dir, e))?;
+            }
+            let json =
+                serde_json::to_string(&Entry::from(answer)).map_err(|e| Error::format(&path, e))?;
+            fs::write(&path, json).map_err(|e| Error::io(&path, e))?;
+        }
+        Ok(())
+    }
+
+    pub fn dir(&self) -> &Path {
+        &self.dir
+    }
+
+    // Only removes the entries written by `put` and the year and day
+    // directories they leave empty, the cache directory may be shared.
+    pub fn clear(&self) -> Result<usize> {
+        let mut removed = 0;
+        for year_dir in subdirs(&self.dir, |name| name.parse::<i32>().is_ok())? {
+            let is_day = |name: &str| {
+                name.strip_prefix("day")
+                    .is_some_and(|day| day.parse::<i32>().is_ok())
+            };
+            for day_dir in subdirs(&year_dir, is_day)? {
+                for path in list(&day_dir)? {
+                    if path.is_file() && path.file_name().is_some_and(is_entry_name) {
+                        fs::remove_file(&path).map_err(|e| Error::io(&path, e))?;
+                        removed += 1;
+                    }
+                }
+                remove_if_empty(&day_dir)?;
+            }
+            remove_if_empty(&year_dir)?;
+        }
+        Ok(removed)
+    }
+}
+
+fn list(dir: &Path) -> Result<Vec<PathBuf>> {
+    let entries = match fs::read_dir(dir) {
+        Ok(entries) => entries,
+        Err(e) if e.kind() == ErrorKind::NotFound => return Ok(Vec::new()),
+        Err(e) => return Err(Error::io(dir, e)),
+    };
+    entries
+        .map(|entry| {
+            entry
+                .map(|entry| entry.path())
+                .map_err(|e| Error::io(dir, e))
+        })
+        .collect()
+}
+
+fn subdirs(dir: &Path, matches: impl Fn(&str) -> bool) -> Result<Vec<PathBuf>> {
+    Ok(list(dir)?
+        .into_iter()
+        .filter(|path| {
+            path.is_dir()
+                && path
+                    .file_name()
+                    .and_then(OsStr::to_str)
+                    .is_some_and(&matches)
+        })
+        .collect())
+}
+
+fn remove_if_empty(dir: &Path) -> Result<()> {
+    if list(dir)?.is_empty() {
+        fs::remove_dir(dir).map_err(|e| Error::io(dir, e))?;
+    }
+    Ok(())
+}
+
+// Names written by `Cache::path`: "<input hash>-<build id>-part<n>.json".
+fn is_entry_name(name: &OsStr) -> bool {
+    let is_hash = |s: &str| s.len() == 16 && s.chars().all(|c| c.is_ascii_hexdigit());
+    name.to_str()
+        .and_then(|name| name.strip_suffix(".json"))
+        .and_then(|name| name.split_once('-'))
+        .and_then(|(input, rest)| Some((input, rest.split_once('-')?)))
+        .is_some_and(|(input, (build, part))| {
+            is_hash(input) && is_hash(build) && matches!(part, "part1" | "part2")
+        })
+}
+
+// Every build rewrites the executable, so its size and modification time
+// change whenever the code that produced the cached answers may have.
+fn build_id() -> Result<u64> {
+    let exe = env::current_exe().map_err(|e| Error::io("<current executable>", e))?;
+    let metadata = fs::metadata(&exe).map_err(|e| Error::io(&exe, e))?;
+    let modified = metadata
+        .modified()
+        .ok()
+        .and_then(|time| time.duration_since(UNIX_EPOCH).ok())
+        .unwrap_or_default();
+
+    let id = format!(
+        "{}:{}:{}",
+        exe.display(),
+        metadata.len(),
+        modified.as_nanos()
+    );
+    Ok(InputHash::of(id.as_bytes()).get())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use advent_of_code::years::{Context, Solver};
+
+    #[test]
+    fn test_get_and_put() {
+        let dir = env::temp_dir().join(format!("aoc-cache-{}", std::process::id()));
+        let cache = Cache::open(dir.clone()).unwrap();
+        let key = Key {
+            year: 2024,
+            day: DayNum::new(17).unwrap(),
+            input: InputHash::of(b"Register A: 729"),
+        };
+        assert!(cache.get(&key, None).is_none());
+
+        let solution = Solver::new(&Context::default(), ())
+            .part1(|_| "4,6,3,5,6,3,5,2,1,0")
+            .part2(|_| u64::MAX)
+            .unwrap();
+        cache.put(&key, Some(1), &solution).unwrap();
+        assert!(cache.get(&key, None).is_none());
+
+        let cached = cache.get(&key, Some(1)).unwrap();
+        assert!(cached.cached);
+        assert_eq!(cached.part1, solution.part1);
+        assert_eq!(cached.part2, Answer::Unsolved);
+        assert_eq!(cached.input_hash, Some(key.input));
+
+        cache.put(&key, None, &solution).unwrap();
+        let cached = cache.get(&key, None).unwrap();
+        assert_eq!(cached.part2, Answer::Number(u64::MAX as i128));
+
+        let rebuilt = Cache {
+            build: cache.build + 1,
+            ..cache.clone()
+        };
+        assert!(rebuilt.get(&key, None).is_none());
+
+        assert_eq!(cache.clear().unwrap(), 2);
+        assert!(!dir.join("2024").exists());
+        assert!(cache.get(&key, Some(1)).is_none());
+        assert_eq!(cache.clear().unwrap(), 0);
+
+        fs::remove_dir_all(dir).unwrap();
+    }
+
+    #[test]
+    fn test_clear_keeps_foreign_files() {
+        let dir = env::temp_dir().join(format!("aoc-cache-shared-{}", std::process::id()));
+        let cache = Cache::open(dir.clone()).unwrap();
+        let key = Key {
+            year: 2025,
+            day: DayNum::new(1).unwrap(),
+            input: InputHash::of(b"L68"),
+        };
+        let solution = Solver::new(&Context::default(), ())
+            .part1(|_| 3)
+            .part2(|_| 6)
+            .unwrap();
+        cache.put(&key, None, &solution).unwrap();
+
+        let day_dir = dir.join("2025").join("day1");
+        fs::write(dir.join("notes.txt"), "keep").unwrap();
+        fs::write(day_dir.join("notes.json"), "keep").unwrap();
+        fs::create_dir_all(dir.join("project")).unwrap();
+
+        assert_eq!(cache.clear().unwrap(), 2);
+        assert!(dir.join("notes.txt").exists());
+        assert!(day_dir.join("notes.json").exists());
+        assert!(dir.join("project").exists());
+
+        fs::remove_dir_all(dir).unwrap();
+    }
+}
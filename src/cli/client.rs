@@ -0,0 +1,289 @@
+use advent_of_code::error::{Error, Result};
+use advent_of_code::years::DayNum;
+use std::env;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::time::Duration;
+use toml::Table;
+
+pub const SESSION_VAR: &str = "AOC_SESSION";
+pub const BASE_URL_VAR: &str = "AOC_BASE_URL";
+pub const CONFIG_VAR: &str = "AOC_CONFIG";
+const DEFAULT_BASE_URL: &str = "https://adventofcode.com";
+const USER_AGENT: &str = concat!(env!("CARGO_PKG_NAME"), "/", env!("CARGO_PKG_VERSION"));
+const TIMEOUT: Duration = Duration::from_secs(30);
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Config {
+    pub session: Option<String>,
+    pub base_url: String,
+    pub contact: Option<String>,
+}
+
+impl Config {
+    pub fn load() -> Result<Config> {
+        let table = match config_path() {
+            Some(path) if path.exists() => fs::read_to_string(&path)
+                .map_err(|e| Error::io(&path, e))?
+                .parse::<Table>()
+                .map_err(|e| Error::format(&path, e))?,
+            _ => Table::new(),
+        };
+
+        Ok(Config::from_sources(
+            &table,
+            env::var(SESSION_VAR).ok(),
+            env::var(BASE_URL_VAR).ok(),
+        ))
+    }
+
+    fn from_sources(table: &Table, session: Option<String>, base_url: Option<String>) -> Config {
+        let get = |key: &str| table.get(key).and_then(|v| v.as_str()).map(str::to_string);
+
+        Config {
+            session: session.or_else(|| get("session")),
+            base_url: base_url
+                .or_else(|| get("base_url"))
+                .unwrap_or_else(|| DEFAULT_BASE_URL.to_string())
+                .trim_end_matches('/')
+                .to_string(),
+            contact: get("contact"),
+        }
+    }
+
+    fn user_agent(&self) -> String {
+        match &self.contact {
+            Some(contact) => format!("{USER_AGENT} (by {contact})"),
+            None => USER_AGENT.to_string(),
+        }
+    }
+}
+
+pub fn config_path() -> Option<PathBuf> {
+    if let Some(path) = env::var_os(CONFIG_VAR) {
+        return Some(PathBuf::from(path));
+    }
+
+    let config_dir = env::var_os("XDG_CONFIG_HOME")
+        .map(PathBuf::from)
+        .or_else(|| env::var_os("HOME").map(|home| Path::new(&home).join(".config")))?;
+    Some(config_dir.join("advent_of_code").join("config.toml"))
+}
+
+pub struct Client {
+    config: Config,
+    agent: ureq::Agent,
+}
+
+impl Client {
+    pub fn new(config: Config) -> Client {
+        let agent = ureq::Agent::config_builder()
+            .http_status_as_error(false)
+            .timeout_global(Some(TIMEOUT))
+            .user_agent(config.user_agent())
+            .build()
+            .into();
+
+        Client { config, agent }
+    }
+
+    fn session(&self) -> Result<&str> {
+        self.config.session.as_deref().ok_or_else(|| {
+            let config = config_path()
+                .map(|path| path.display().to_string())
+                .unwrap_or_else(|| "the config file".to_string());
+            Error::usage(format!(
+                "no session token, set {SESSION_VAR} or 'session' in {config}"
+            ))
+        })
+    }
+
+    fn url(&self, path: &str) -> String {
+        format!("{}{path}", self.config.base_url)
+    }
+
+    fn get(&self, path: &str) -> Result<String> {
+        let url = self.url(path);
+        let response = self
+            .agent
+            .get(&url)
+            .header("Cookie", format!("session={}", self.session()?))
+            .call()
+            .map_err(|e| Error::http(&url, e))?;
+
+        read_body(&url, response)
+    }
+
+    fn post_form(&self, path: &str, form: &[(&str, &str)]) -> Result<String> {
+        let url = self.url(path);
+        let response = self
+            .agent
+            .post(&url)
+            .header("Cookie", format!("session={}", self.session()?))
+            .send_form(form.iter().copied())
+            .map_err(|e| Error::http(&url, e))?;
+
+        read_body(&url, response)
+    }
+
+    pub fn fetch_input(&self, year: i32, day: DayNum) -> Result<String> {
+        self.get(&format!("/{year}/day/{day}/input"))
+    }
+
+    pub fn submit_answer(
+        &self,
+        year: i32,
+        day: DayNum,
+        part: usize,
+        answer: &str,
+    ) -> Result<String> {
+        self.post_form(
+            &format!("/{year}/day/{day}/answer"),
+            &[("level", &part.to_string()), ("answer", answer)],
+        )
+    }
+}
+
+fn read_body(url: &str, mut response: ureq::http::Response<ureq::Body>) -> Result<String> {
+    match response.status().as_u16() {
+        200 => response
+            .body_mut()
+            .read_to_string()
+            .map_err(|e| Error::http(url, e)),
+        400 | 401 => Err(Error::http(url, "the session token was rejected")),
+        404 => Err(Error::http(url, "the puzzle is not available yet")),
+        status => Err(Error::http(url, format!("unexpected status {status}"))),
+    }
+}
+
+pub fn download_input(client: &Client, year: i32, day: DayNum, path: &Path) -> Result<()> {
+    let input = client.fetch_input(year, day)?;
+    if let Some(dir) = path.parent() {
+        fs::create_dir_all(dir).map_err(|e| Error::io(dir, e))?;
+    }
+    fs::write(path, input).map_err(|e| Error::io(path, e))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::io::{BufRead, BufReader, Read, Write};
+    use std::net::TcpListener;
+    use std::thread;
+
+    fn serve_once(
+        status: &'static str,
+        body: &'static str,
+    ) -> (String, thread::JoinHandle<String>) {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let base_url = format!("http://{}", listener.local_addr().unwrap());
+
+        let handle = thread::spawn(move || {
+            let (mut stream, _) = listener.accept().unwrap();
+            let mut request = String::new();
+            let mut reader = BufReader::new(stream.try_clone().unwrap());
+            loop {
+                let mut line = String::new();
+                reader.read_line(&mut line).unwrap();
+                if line.trim().is_empty() {
+                    break;
+                }
+                request += &line;
+            }
+
+            let content_length = request
+                .lines()
+                .find_map(|line| {
+                    line.to_ascii_lowercase()
+                        .strip_prefix("content-length: ")?
+                        .parse()
+                        .ok()
+                })
+                .unwrap_or(0);
+            let mut body_bytes = vec![0; content_length];
+            reader.read_exact(&mut body_bytes).unwrap();
+            request += &String::from_utf8(body_bytes).unwrap();
+
+            write!(
+                stream,
+                "HTTP/1.1 {status}\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{body}",
+                body.len()
+            )
+            .unwrap();
+            request
+        });
+
+        (base_url, handle)
+    }
+
+    fn test_client(base_url: String) -> Client {
+        Client::new(Config {
+            session: Some("secret".to_string()),
+            base_url,
+            contact: Some("team@example.com".to_string()),
+        })
+    }
+
+    #[test]
+    fn test_config_sources() {
+        let table: Table = "session = \"from-file\"\nbase_url = \"http://localhost:8080/\""
+            .parse()
+            .unwrap();
+
+        let config = Config::from_sources(&table, None, None);
+        assert_eq!(config.session.as_deref(), Some("from-file"));
+        assert_eq!(config.base_url, "http://localhost:8080");
+
+        let config = Config::from_sources(&table, Some("from-env".to_string()), None);
+        assert_eq!(config.session.as_deref(), Some("from-env"));
+
+        let config = Config::from_sources(&Table::new(), None, None);
+        assert_eq!(config.base_url, DEFAULT_BASE_URL);
+        assert_eq!(config.session, None);
+    }
+
+    #[test]
+    fn test_download_input() {
+        let (base_url, handle) = serve_once("200 OK", "1,2,3\n4,5,6\n");
+        let dir = env::temp_dir().join(format!("aoc-fetch-{}", std::process::id()));
+        let path = dir.join("2025").join("day9.txt");
+
+        download_input(&test_client(base_url), 2025, DayNum::new(9).unwrap(), &path).unwrap();
+        assert_eq!(fs::read_to_string(&path).unwrap(), "1,2,3\n4,5,6\n");
+
+        let request = handle.join().unwrap();
+        assert!(request.starts_with("GET /2025/day/9/input HTTP/1.1"));
+        assert!(request.contains("session=secret"));
+        assert!(request.contains("advent_of_code/0.1.0 (by team@example.com)"));
+
+        fs::remove_dir_all(dir).unwrap();
+    }
+
+    #[test]
+    fn test_fetch_not_available() {
+        let (base_url, handle) = serve_once("404 Not Found", "");
+        let error = test_client(base_url)
+            .fetch_input(2025, DayNum::new(25).unwrap())
+            .unwrap_err();
+        handle.join().unwrap();
+        assert!(
+            error
+                .to_string()
+                .ends_with("the puzzle is not available yet")
+        );
+    }
+
+    #[test]
+    fn test_submit_answer() {
+        let (base_url, handle) = serve_once("200 OK", "<p>That's the right answer!</p>");
+        let response = test_client(base_url)
+            .submit_answer(2024, DayNum::new(17).unwrap(), 2, "117440")
+            .unwrap();
+        assert_eq!(response, "<p>That's the right answer!</p>");
+
+        let request = handle.join().unwrap();
+        assert!(request.starts_with("POST /2024/day/17/answer HTTP/1.1"));
+        assert!(request.contains("session=secret"));
+        assert!(request.ends_with("level=2&answer=117440"));
+    }
+}
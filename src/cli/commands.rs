@@ -0,0 +1,294 @@
+use super::answers::{ANSWERS_PATH, Answers, Verdict};
+use super::args::{Options, help, parse_day, parse_day_num, parse_option, parse_year, take_option};
+use super::cache::{self, Cache};
+use super::client::{Client, Config};
+use super::input::{self, InputSource};
+use super::solve::{select_days_with_input, solve_day};
+use super::submit::{self, GUESSES_PATH, Guesses, Outcome};
+use super::{bench, client, pool, scaffold};
+use advent_of_code::error::{Error, Result};
+use advent_of_code::years::{self, Answer, Context, DayNum, get_year};
+use std::path::Path;
+use std::process;
+
+pub fn list_days() {
+    for year in years::YEARS {
+        let days = year
+            .days()
+            .iter()
+            .map(|(day, _)| day.to_string())
+            .collect::<Vec<String>>()
+            .join(", ");
+        let range = year.day_range();
+        println!(
+            "{}: {days} ({} of {} days)",
+            year.year(),
+            year.days().len(),
+            range.end() - range.start() + 1
+        );
+    }
+}
+
+// answers.toml holds the answers of the default inputs and parameters, so
+// comparing or recording the answers of any other run would mix them up.
+fn require_default_input(opts: &Options, command: &str) -> Result<()> {
+    if opts.input != InputSource::Default {
+        return Err(Error::usage(format!(
+            "{command} only works on the default inputs, --input and --example cannot be used"
+        )));
+    }
+    if !opts.ctx.params.is_empty() {
+        return Err(Error::usage(format!(
+            "{command} only works with the default parameters, --param cannot be used"
+        )));
+    }
+    Ok(())
+}
+
+pub fn verify_days(args: &[String], opts: &Options) -> Result<()> {
+    require_default_input(opts, "verify")?;
+    let answers = Answers::load(ANSWERS_PATH)?;
+    let mut failures = 0;
+
+    let selected = select_days_with_input(args, opts)?;
+    let solutions = pool::map(&selected, opts.jobs, |&(year, day, advent_day)| {
+        solve_day(year, day, advent_day, opts)
+    });
+
+    for (&(year, day, _), solution) in selected.iter().zip(solutions) {
+        let solution = match solution {
+            Ok(solution) => solution,
+            Err(e) => {
+                failures += 1;
+                println!("{year} day {day}: ERROR ({e})");
+                continue;
+            }
+        };
+        let parts = [(1, &solution.part1), (2, &solution.part2)]
+            .into_iter()
+            .filter_map(|(part, answer)| {
+                let verdict = answers.verify(year, day, part, answer)?;
+                if verdict == Verdict::Fail {
+                    failures += 1;
+                    let expected = answers.get(year, day, part).unwrap_or_default();
+                    Some(format!(
+                        "part{part} {verdict} (expected {expected}, got {answer})"
+                    ))
+                } else {
+                    Some(format!("part{part} {verdict}"))
+                }
+            })
+            .collect::<Vec<String>>();
+
+        println!("{year} day {day}: {}", parts.join(", "));
+    }
+
+    if failures > 0 {
+        println!("\n{failures} answer(s) did not match {ANSWERS_PATH}");
+        process::exit(1);
+    }
+    Ok(())
+}
+
+pub fn record_days(args: &[String], opts: &Options) -> Result<()> {
+    if args.is_empty() {
+        help();
+        return Ok(());
+    }
+
+    require_default_input(opts, "record")?;
+
+    let mut answers = Answers::load(ANSWERS_PATH)?;
+    for (year, day, advent_day) in select_days_with_input(args, opts)? {
+        let solution = solve_day(year, day, advent_day, opts)?;
+        answers.record(year, day, &solution)?;
+        println!("Recorded {year} day {day}:\n{solution}");
+    }
+    answers.save(ANSWERS_PATH)
+}
+
+pub fn bench_days(args: &[String], opts: &Options) -> Result<()> {
+    let mut args = args.to_vec();
+    let runs =
+        parse_option(take_option(&mut args, "--runs")?, "--runs")?.unwrap_or(bench::DEFAULT_RUNS);
+    let threshold = parse_option(take_option(&mut args, "--threshold")?, "--threshold")?
+        .unwrap_or(bench::DEFAULT_THRESHOLD);
+    let save_path = take_option(&mut args, "--save")?;
+    let previous = match take_option(&mut args, "--compare")? {
+        Some(path) => bench::load(&path)?,
+        None => Vec::new(),
+    };
+
+    if args.is_empty() {
+        help();
+        return Ok(());
+    }
+
+    let mut results = Vec::new();
+    let mut regressions = 0;
+    for (year, day, advent_day) in select_days_with_input(&args, opts)? {
+        let input = opts.input.read(advent_day)?;
+        input.validate(year, day, advent_day)?;
+        let result = bench::bench_day(year, day, advent_day, &input, &opts.ctx, runs)?;
+        let previous = previous
+            .iter()
+            .find(|p| p.year == result.year && p.day == result.day);
+        if bench::print_result(&result, previous, threshold) {
+            regressions += 1;
+        }
+        results.push(result);
+    }
+
+    if let Some(path) = save_path {
+        bench::save(&path, &results)?;
+    }
+
+    if regressions > 0 {
+        println!("\n{regressions} day(s) regressed by more than {threshold}%");
+        process::exit(1);
+    }
+    Ok(())
+}
+
+pub fn fetch_input(args: &[String]) -> Result<()> {
+    let [year, day] = args else {
+        help();
+        return Ok(());
+    };
+    let year = year
+        .parse::<i32>()
+        .map_err(|_| Error::usage(format!("year should be a number, got '{year}'")))?;
+    let day = match get_year(year) {
+        Some(known_year) => parse_day(known_year, day)?,
+        None => DayNum::new(parse_day_num(day)?)
+            .ok_or_else(|| Error::usage(format!("day should be between 1 and 25, got '{day}'")))?,
+    };
+
+    let input_path = get_year(year)
+        .and_then(|y| y.get_day(day))
+        .map(|advent_day| advent_day.get_input_path().to_string())
+        .unwrap_or_else(|| input::default_input_path(year, day));
+    let path = input::resolve_path(&input_path);
+    if path.exists() {
+        println!("{year} day {day} is already cached at {}", path.display());
+        return Ok(());
+    }
+
+    let client = Client::new(Config::load()?);
+    client::download_input(&client, year, day, &path)?;
+    println!("Downloaded {year} day {day} to {}", path.display());
+    Ok(())
+}
+
+pub fn new_day(args: &[String]) -> Result<()> {
+    let [year, day] = args else {
+        help();
+        return Ok(());
+    };
+    let year = parse_year(year)?;
+    let day = parse_day(year, day)?;
+    let year = year.year();
+
+    let path = scaffold::create_day(Path::new(scaffold::YEARS_DIR), year, day)?;
+    println!("Created {} and registered {year} day {day}", path.display());
+    Ok(())
+}
+
+pub fn submit_answer(args: &[String], opts: &Options) -> Result<()> {
+    let [year, day, part] = args else {
+        help();
+        return Ok(());
+    };
+    let year = parse_year(year)?;
+    let day = parse_day(year, day)?;
+    let part = match part.as_str() {
+        "1" => 1,
+        "2" => 2,
+        _ => {
+            return Err(Error::usage(format!("part should be 1 or 2, got '{part}'")));
+        }
+    };
+
+    require_default_input(opts, "submit")?;
+    let advent_day = year.find_day(day)?;
+    let year = year.year();
+
+    let ctx = Context {
+        part: Some(part),
+        ..opts.ctx.clone()
+    };
+    let input = opts.input.read(advent_day)?;
+    input.validate(year, day, advent_day)?;
+    let solution = input.solve(advent_day, &ctx)?;
+    let answer = if part == 1 {
+        solution.part1
+    } else {
+        solution.part2
+    };
+    if answer == Answer::Unsolved {
+        return Err(Error::usage(format!(
+            "{year} day {day} part{part} has no answer to submit"
+        )));
+    }
+
+    let mut guesses = Guesses::load(GUESSES_PATH)?;
+    if let Some(guess) = guesses.find(year, day, part, &answer) {
+        let reason = match guess.hint {
+            Some(hint) if guess.answer != answer.to_string() => {
+                format!("{} was already {hint}", guess.answer)
+            }
+            _ => "it was already submitted".to_string(),
+        };
+        return Err(Error::usage(format!(
+            "not submitting {answer} for {year} day {day} part{part}, {reason}"
+        )));
+    }
+
+    let client = Client::new(Config::load()?);
+    let outcome =
+        submit::parse_response(&client.submit_answer(year, day, part, &answer.to_string())?);
+    println!("{year} day {day} part{part}: {answer}\n{outcome}");
+
+    match outcome {
+        Outcome::Correct => {
+            let mut answers = Answers::load(ANSWERS_PATH)?;
+            answers.record_part(year, day, part, &answer)?;
+            answers.save(ANSWERS_PATH)
+        }
+        Outcome::Wrong(hint) => {
+            guesses.add(year, day, part, &answer, hint);
+            guesses.save(GUESSES_PATH)?;
+            process::exit(1);
+        }
+        Outcome::RateLimited(_) | Outcome::Unknown => process::exit(1),
+        Outcome::AlreadyCompleted => Ok(()),
+    }
+}
+
+pub fn cache_command(args: &[String], opts: &Options) -> Result<()> {
+    let [command] = args else {
+        help();
+        return Ok(());
+    };
+    if command != "clear" {
+        return Err(Error::usage(format!(
+            "unknown cache command '{command}', expected 'clear'"
+        )));
+    }
+
+    let cache = match &opts.cache {
+        Some(cache) => cache.clone(),
+        None => Cache::from_env()?.ok_or_else(|| {
+            Error::usage(format!(
+                "the answer cache is disabled, set {} to enable it",
+                cache::CACHE_DIR_VAR
+            ))
+        })?,
+    };
+    let removed = cache.clear()?;
+    println!(
+        "Removed {removed} cached answer(s) from {}",
+        cache.dir().display()
+    );
+    Ok(())
+}
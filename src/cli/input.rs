@@ -0,0 +1,241 @@
+use advent_of_code::error::{Error, Result};
+use advent_of_code::library::parse_file;
+use advent_of_code::library::text::InputText;
+use advent_of_code::years::{self, AdventDay, Context, DayNum, Solution};
+use std::env;
+use std::ffi::OsStr;
+use std::fs;
+use std::io::{self, Read};
+use std::path::{Path, PathBuf};
+
+pub const INPUT_DIR_VAR: &str = "AOC_INPUT_DIR";
+const DEFAULT_INPUT_DIR: &str = "Inputs";
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum InputSource {
+    Default,
+    File(String),
+    Stdin,
+    Example(String),
+}
+
+pub struct Input {
+    pub text: InputText,
+    pub path: Option<PathBuf>,
+    pub params: &'static [(&'static str, &'static str)],
+}
+
+impl Input {
+    fn with_path(&self, error: Error) -> Error {
+        match &self.path {
+            Some(path) => error.with_file(path),
+            None => error,
+        }
+    }
+
+    pub fn validate(&self, year: i32, day: DayNum, advent_day: &dyn AdventDay) -> Result<()> {
+        years::validate_input(year, day, advent_day, &self.text).map_err(|e| self.with_path(e))
+    }
+
+    pub fn solve(&self, advent_day: &dyn AdventDay, ctx: &Context) -> Result<Solution> {
+        let ctx = ctx.resolve(advent_day.params(), self.params)?;
+        let mut solution = advent_day
+            .solve_input(&self.text, &ctx)
+            .map_err(|e| self.with_path(e))?;
+        solution.input_hash = Some(self.text.hash());
+        Ok(solution)
+    }
+}
+
+impl InputSource {
+    pub fn from_arg(arg: Option<String>) -> InputSource {
+        match arg.as_deref() {
+            None => InputSource::Default,
+            Some("-") => InputSource::Stdin,
+            Some(path) => InputSource::File(path.to_string()),
+        }
+    }
+
+    pub fn read(&self, advent_day: &dyn AdventDay) -> Result<Input> {
+        let path = match self {
+            InputSource::Default => resolve_path(advent_day.get_input_path()),
+            InputSource::File(path) => PathBuf::from(path),
+            InputSource::Stdin => {
+                let mut input = String::new();
+                io::stdin()
+                    .read_to_string(&mut input)
+                    .map_err(|source| Error::Io {
+                        path: PathBuf::from("<stdin>"),
+                        source,
+                    })?;
+                return Ok(Input {
+                    text: InputText::new(input),
+                    path: None,
+                    params: &[],
+                });
+            }
+            InputSource::Example(name) => {
+                let example = advent_day
+                    .get_example(name)
+                    .ok_or_else(|| unknown_example(advent_day, name))?;
+                return Ok(Input {
+                    text: InputText::from(example.input),
+                    path: None,
+                    params: example.params,
+                });
+            }
+        };
+
+        Ok(Input {
+            text: read_file(&path)?,
+            path: Some(path),
+            params: &[],
+        })
+    }
+}
+
+fn unknown_example(advent_day: &dyn AdventDay, name: &str) -> Error {
+    let names = advent_day
+        .examples()
+        .iter()
+        .map(|example| example.name)
+        .collect::<Vec<&str>>();
+    if names.is_empty() {
+        Error::usage("this day has no examples")
+    } else {
+        Error::usage(format!(
+            "no example named '{name}', available: {}",
+            names.join(", ")
+        ))
+    }
+}
+
+pub fn read_file(path: &Path) -> Result<InputText> {
+    match parse_file(path) {
+        Ok(buffer) => Ok(InputText::new(buffer)),
+        Err(source) => Err(Error::Io {
+            path: path.to_path_buf(),
+            source,
+        }),
+    }
+}
+
+pub fn list_dir(dir: &Path) -> Result<Vec<PathBuf>> {
+    let mut paths = Vec::new();
+    for entry in fs::read_dir(dir).map_err(|e| Error::io(dir, e))? {
+        let path = entry.map_err(|e| Error::io(dir, e))?.path();
+        if path.is_file() {
+            paths.push(path);
+        }
+    }
+
+    if paths.is_empty() {
+        return Err(Error::usage(format!(
+            "{} does not contain any input files",
+            dir.display()
+        )));
+    }
+    paths.sort();
+    Ok(paths)
+}
+
+pub fn default_input_path(year: i32, day: DayNum) -> String {
+    format!("{DEFAULT_INPUT_DIR}/{year}/day{day}.txt")
+}
+
+pub fn resolve_path(path: &str) -> PathBuf {
+    resolve_path_in(path, env::var_os(INPUT_DIR_VAR).as_deref())
+}
+
+fn resolve_path_in(path: &str, input_dir: Option<&OsStr>) -> PathBuf {
+    let path = Path::new(path);
+    match (input_dir, path.strip_prefix(DEFAULT_INPUT_DIR)) {
+        (Some(input_dir), Ok(relative_path)) => Path::new(input_dir).join(relative_path),
+        _ => path.to_path_buf(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_resolve_path() {
+        assert_eq!(
+            resolve_path_in("Inputs/2024/day17.txt", None),
+            PathBuf::from("Inputs/2024/day17.txt")
+        );
+        assert_eq!(
+            resolve_path_in("Inputs/2024/day17.txt", Some(OsStr::new("/tmp/aoc"))),
+            PathBuf::from("/tmp/aoc/2024/day17.txt")
+        );
+    }
+
+    #[test]
+    fn test_default_input_path() {
+        let day = DayNum::new(9).unwrap();
+        assert_eq!(default_input_path(2025, day), "Inputs/2025/day9.txt");
+    }
+
+    #[test]
+    fn test_input_source_from_arg() {
+        assert_eq!(InputSource::from_arg(None), InputSource::Default);
+        assert_eq!(
+            InputSource::from_arg(Some("-".to_string())),
+            InputSource::Stdin
+        );
+        assert_eq!(
+            InputSource::from_arg(Some("example.txt".to_string())),
+            InputSource::File("example.txt".to_string())
+        );
+    }
+
+    #[cfg(feature = "year2024")]
+    #[test]
+    fn test_read_example() {
+        let advent_day = advent_of_code::years::get_year(2024)
+            .and_then(|year| year.get_day(DayNum::new(15).unwrap()))
+            .unwrap();
+
+        let input = InputSource::Example("small".to_string())
+            .read(advent_day)
+            .unwrap();
+        assert_eq!(input.text.lines().next(), Some("########"));
+        assert_eq!(input.path, None);
+
+        let error = InputSource::Example("huge".to_string())
+            .read(advent_day)
+            .err()
+            .unwrap();
+        assert_eq!(
+            error.to_string(),
+            "no example named 'huge', available: smallest, small, large"
+        );
+    }
+
+    #[test]
+    fn test_read_missing_file() {
+        let error = read_file(Path::new("Inputs/1999/day1.txt")).unwrap_err();
+        assert!(
+            error
+                .to_string()
+                .starts_with("could not read Inputs/1999/day1.txt")
+        );
+    }
+
+    #[test]
+    fn test_list_dir() {
+        let dir = env::temp_dir().join(format!("aoc-inputs-{}", std::process::id()));
+        fs::create_dir_all(dir.join("nested")).unwrap();
+        assert!(list_dir(&dir).is_err());
+
+        fs::write(dir.join("bob.txt"), "2").unwrap();
+        fs::write(dir.join("alice.txt"), "1").unwrap();
+        assert_eq!(
+            list_dir(&dir).unwrap(),
+            vec![dir.join("alice.txt"), dir.join("bob.txt")]
+        );
+
+        fs::remove_dir_all(dir).unwrap();
+    }
+}
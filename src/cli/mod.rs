@@ -0,0 +1,77 @@
+#[cfg(feature = "alloc-stats")]
+mod alloc;
+mod answers;
+mod args;
+mod bench;
+mod cache;
+mod client;
+mod commands;
+mod input;
+mod pool;
+mod report;
+mod scaffold;
+mod solve;
+mod submit;
+
+use advent_of_code::error::{Error, Result};
+use advent_of_code::log;
+use args::{COMMANDS, Options, help, take_flag};
+use commands::{
+    bench_days, cache_command, fetch_input, list_days, new_day, record_days, submit_answer,
+    verify_days,
+};
+use report::Format;
+use solve::{solve_days, solve_single_day};
+
+pub fn run(args: Vec<String>) -> Result<()> {
+    pool::install_panic_hook();
+    pool::catch_panic(|| dispatch(args)).unwrap_or_else(|message| Err(Error::Panic(message)))
+}
+
+fn dispatch(mut args: Vec<String>) -> Result<()> {
+    let verbosity = 2 * take_flag(&mut args, "-vv") + take_flag(&mut args, "-v");
+    log::set_level(log::Level::from_verbosity(verbosity));
+
+    let opts = Options::parse(&mut args)?;
+
+    if args.is_empty() {
+        help();
+        return Ok(());
+    }
+
+    let command = args[0].as_str();
+    if opts.format == Format::Json && COMMANDS.contains(&command) {
+        return Err(Error::usage(format!(
+            "--format json is only supported when solving days, not for {command}"
+        )));
+    }
+    if opts.inputs_dir.is_some() {
+        if COMMANDS.contains(&command) || args.len() < 2 {
+            return Err(Error::usage(
+                "--inputs-dir can only be used when solving a single day",
+            ));
+        }
+        if opts.format == Format::Json {
+            return Err(Error::usage(
+                "--format json is not supported with --inputs-dir",
+            ));
+        }
+    }
+
+    match command {
+        "list" => {
+            list_days();
+            Ok(())
+        }
+        "verify" => verify_days(&args[1..], &opts),
+        "record" => record_days(&args[1..], &opts),
+        "bench" => bench_days(&args[1..], &opts),
+        "fetch" => fetch_input(&args[1..]),
+        "new" => new_day(&args[1..]),
+        "submit" => submit_answer(&args[1..], &opts),
+        "cache" => cache_command(&args[1..], &opts),
+        "all" => solve_days(&args, &opts),
+        _ if args.len() < 2 => solve_days(&args, &opts),
+        _ => solve_single_day(&args, &opts),
+    }
+}
@@ -0,0 +1,147 @@
+use std::any::Any;
+use std::panic::{self, AssertUnwindSafe};
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::{Mutex, mpsc};
+use std::thread;
+use std::time::Duration;
+
+pub fn default_jobs() -> usize {
+    thread::available_parallelism()
+        .map(|jobs| jobs.get())
+        .unwrap_or(1)
+}
+
+// Applies `f` to every item on up to `jobs` threads. Results are returned in
+// the order of `items`, regardless of which thread finished first.
+pub fn map<T: Sync, R: Send>(items: &[T], jobs: usize, f: impl Fn(&T) -> R + Sync) -> Vec<R> {
+    let next = AtomicUsize::new(0);
+    let results = Mutex::new(Vec::with_capacity(items.len()));
+
+    thread::scope(|scope| {
+        for _ in 0..jobs.clamp(1, items.len().max(1)) {
+            scope.spawn(|| {
+                loop {
+                    let idx = next.fetch_add(1, Ordering::Relaxed);
+                    let Some(item) = items.get(idx) else {
+                        break;
+                    };
+                    let result = f(item);
+                    results.lock().unwrap().push((idx, result));
+                }
+            });
+        }
+    });
+
+    let mut results = results.into_inner().unwrap();
+    results.sort_by_key(|(idx, _)| *idx);
+    results.into_iter().map(|(_, result)| result).collect()
+}
+
+static RUNNING: AtomicUsize = AtomicUsize::new(0);
+
+struct Running;
+
+impl Drop for Running {
+    fn drop(&mut self) {
+        RUNNING.fetch_sub(1, Ordering::Relaxed);
+    }
+}
+
+// Threads started by `run_with_timeout` that have not finished yet. Once every
+// day was waited for, these are the ones that timed out and ignored the
+// cancellation.
+pub fn still_running() -> usize {
+    RUNNING.load(Ordering::Relaxed)
+}
+
+// Runs `f` on its own thread and gives up waiting after `timeout`. A thread
+// cannot be killed, so a day that times out keeps running in the background
+// until it checks for cancellation or the process exits.
+pub fn run_with_timeout<R: Send + 'static>(
+    timeout: Duration,
+    f: impl FnOnce() -> R + Send + 'static,
+) -> Option<R> {
+    let (sender, receiver) = mpsc::channel();
+    RUNNING.fetch_add(1, Ordering::Relaxed);
+    let handle = thread::spawn(move || {
+        let result = {
+            let _running = Running;
+            f()
+        };
+        let _ = sender.send(result);
+    });
+
+    match receiver.recv_timeout(timeout) {
+        Ok(result) => Some(result),
+        Err(mpsc::RecvTimeoutError::Timeout) => None,
+        Err(mpsc::RecvTimeoutError::Disconnected) => match handle.join() {
+            Err(payload) => panic::resume_unwind(payload),
+            Ok(()) => unreachable!("the result is sent before the thread finishes"),
+        },
+    }
+}
+
+// Panics are reported as errors by `catch_panic`, the default hook would only
+// repeat them with a backtrace. Their location is still logged with -v.
+pub fn install_panic_hook() {
+    panic::set_hook(Box::new(|info| {
+        if let Some(location) = info.location() {
+            advent_of_code::debug!("panicked at {location}");
+        }
+    }));
+}
+
+pub fn catch_panic<R>(f: impl FnOnce() -> R) -> Result<R, String> {
+    panic::catch_unwind(AssertUnwindSafe(f)).map_err(|payload| panic_message(&*payload))
+}
+
+fn panic_message(payload: &(dyn Any + Send)) -> String {
+    if let Some(message) = payload.downcast_ref::<&str>() {
+        message.to_string()
+    } else if let Some(message) = payload.downcast_ref::<String>() {
+        message.clone()
+    } else {
+        "unknown panic".to_string()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::time::Duration;
+
+    #[test]
+    fn test_map_keeps_order() {
+        let items: Vec<u64> = (0..20).collect();
+        let results = map(&items, 4, |&i| {
+            thread::sleep(Duration::from_millis(20 - i));
+            i * 2
+        });
+        assert_eq!(results, (0..20).map(|i| i * 2).collect::<Vec<u64>>());
+
+        assert_eq!(map(&items, 0, |&i| i), items);
+        assert!(map(&[] as &[u64], 4, |&i| i).is_empty());
+    }
+
+    #[test]
+    fn test_run_with_timeout() {
+        assert_eq!(run_with_timeout(Duration::from_secs(5), || 42), Some(42));
+        assert_eq!(
+            run_with_timeout(Duration::from_millis(10), || {
+                thread::sleep(Duration::from_secs(1));
+                42
+            }),
+            None
+        );
+        assert!(still_running() >= 1);
+    }
+
+    #[test]
+    fn test_catch_panic() {
+        assert_eq!(catch_panic(|| 42), Ok(42));
+        assert_eq!(
+            catch_panic(|| -> u32 { panic!("Got unexpected char '{}'", 'X') }),
+            Err("Got unexpected char 'X'".to_string())
+        );
+    }
+}
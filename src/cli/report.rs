@@ -0,0 +1,425 @@
+use advent_of_code::alloc::{self, AllocStats};
+use advent_of_code::error::{Error, Result};
+use advent_of_code::years::{Answer, DayNum, Solution};
+use serde::Serialize;
+use serde_json::Value;
+use std::path::PathBuf;
+use std::time::Duration;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Format {
+    Text,
+    Json,
+}
+
+impl Format {
+    pub fn from_arg(arg: Option<String>) -> Result<Format> {
+        match arg.as_deref() {
+            None | Some("text") => Ok(Format::Text),
+            Some("json") => Ok(Format::Json),
+            Some(format) => Err(Error::usage(format!(
+                "--format should be text or json, got '{format}'"
+            ))),
+        }
+    }
+}
+
+pub struct DayResult {
+    pub year: i32,
+    pub day: DayNum,
+    pub solution: Result<Solution>,
+}
+
+pub fn format_duration(duration: Duration) -> String {
+    let micros = duration.as_secs_f64() * 1_000_000.0;
+    if micros < 1_000.0 {
+        format!("{micros:.1}µs")
+    } else if micros < 1_000_000.0 {
+        format!("{:.2}ms", micros / 1_000.0)
+    } else {
+        format!("{:.3}s", micros / 1_000_000.0)
+    }
+}
+
+pub fn format_bytes(bytes: u64) -> String {
+    let bytes = bytes as f64;
+    if bytes < 1024.0 {
+        format!("{bytes}B")
+    } else if bytes < 1024.0 * 1024.0 {
+        format!("{:.1}KiB", bytes / 1024.0)
+    } else if bytes < 1024.0 * 1024.0 * 1024.0 {
+        format!("{:.1}MiB", bytes / (1024.0 * 1024.0))
+    } else {
+        format!("{:.2}GiB", bytes / (1024.0 * 1024.0 * 1024.0))
+    }
+}
+
+fn format_alloc_stats(stats: &AllocStats) -> String {
+    format!(
+        "{} allocs, {}, peak {}",
+        stats.allocations,
+        format_bytes(stats.bytes),
+        format_bytes(stats.peak)
+    )
+}
+
+pub fn print_solution(solution: &Solution) {
+    println!("{solution}");
+    if let Some(hash) = solution.input_hash {
+        println!("Input hash: {hash}");
+    }
+    if solution.cached {
+        println!("Answers loaded from the cache");
+        return;
+    }
+    println!(
+        "Parse: {}, Part1: {}, Part2: {}",
+        format_duration(solution.timings.parse),
+        format_duration(solution.timings.part1),
+        format_duration(solution.timings.part2)
+    );
+    if alloc::ENABLED {
+        println!(
+            "Memory parse: {}\nMemory part1: {}\nMemory part2: {}",
+            format_alloc_stats(&solution.memory.parse),
+            format_alloc_stats(&solution.memory.part1),
+            format_alloc_stats(&solution.memory.part2)
+        );
+    }
+}
+
+fn status_label(error: &Error) -> &'static str {
+    match error {
+        Error::Timeout(_) => "TIMEOUT",
+        Error::Panic(_) => "PANIC",
+        _ => "ERROR",
+    }
+}
+
+fn solution_cells(solution: &Result<Solution>) -> Vec<String> {
+    let mut cells = match solution {
+        Ok(solution) if solution.cached => vec![
+            solution.part1.to_string(),
+            "cached".to_string(),
+            solution.part2.to_string(),
+            "cached".to_string(),
+            "cached".to_string(),
+            "cached".to_string(),
+        ],
+        Ok(solution) => vec![
+            solution.part1.to_string(),
+            format_duration(solution.timings.part1),
+            solution.part2.to_string(),
+            format_duration(solution.timings.part2),
+            format_duration(solution.timings.parse),
+            format_bytes(solution.memory.peak()),
+        ],
+        Err(e) => vec![
+            status_label(e).to_string(),
+            String::new(),
+            status_label(e).to_string(),
+            String::new(),
+            String::new(),
+            String::new(),
+        ],
+    };
+    if !alloc::ENABLED {
+        cells.pop();
+    }
+    cells
+}
+
+fn print_table(keys: &[&str], rows: Vec<(Vec<String>, &Result<Solution>)>) {
+    let mut header = keys.to_vec();
+    header.extend(["Part1", "Time", "Part2", "Time", "Parse"]);
+    if alloc::ENABLED {
+        header.push("Peak");
+    }
+    let header = header
+        .into_iter()
+        .map(|h| h.to_string())
+        .collect::<Vec<String>>();
+    let rows = rows
+        .into_iter()
+        .map(|(mut row, solution)| {
+            row.extend(solution_cells(solution));
+            row
+        })
+        .collect::<Vec<Vec<String>>>();
+
+    let mut widths = header.iter().map(|h| h.chars().count()).collect::<Vec<_>>();
+    for row in &rows {
+        for (width, cell) in widths.iter_mut().zip(row) {
+            *width = (*width).max(cell.chars().count());
+        }
+    }
+
+    let format_row = |cells: &[String]| {
+        cells
+            .iter()
+            .zip(&widths)
+            .map(|(cell, &width)| format!("{cell:<width$}"))
+            .collect::<Vec<String>>()
+            .join(" | ")
+    };
+
+    let header_line = format_row(&header);
+    println!("{header_line}");
+    println!("{}", "-".repeat(header_line.chars().count()));
+    for row in &rows {
+        println!("{}", format_row(row));
+    }
+}
+
+fn total_time<'a>(solutions: impl Iterator<Item = &'a Result<Solution>>) -> (usize, Duration) {
+    solutions
+        .filter_map(|solution| solution.as_ref().ok())
+        .fold((0, Duration::ZERO), |(count, total), solution| {
+            (count + 1, total + solution.timings.total())
+        })
+}
+
+pub fn print_summary(results: &[DayResult], wall_clock: Duration, jobs: usize, running: usize) {
+    print_table(
+        &["Year", "Day"],
+        results
+            .iter()
+            .map(|r| (vec![r.year.to_string(), r.day.to_string()], &r.solution))
+            .collect(),
+    );
+
+    let (solved, total) = total_time(results.iter().map(|r| &r.solution));
+    println!(
+        "\n{solved} days solved in {} ({} wall-clock on {jobs} thread(s))",
+        format_duration(total),
+        format_duration(wall_clock)
+    );
+    if running > 0 {
+        println!("{running} timed out day(s) still running in the background");
+    }
+
+    for result in results {
+        if let Err(e) = &result.solution {
+            println!("error: {} day {}: {e}", result.year, result.day);
+        }
+    }
+}
+
+pub struct InputResult {
+    pub path: PathBuf,
+    pub solution: Result<Solution>,
+}
+
+pub fn print_input_results(results: &[InputResult]) {
+    let name = |result: &InputResult| {
+        result
+            .path
+            .file_name()
+            .map(|name| name.to_string_lossy().to_string())
+            .unwrap_or_else(|| result.path.display().to_string())
+    };
+
+    let hash = |result: &InputResult| match &result.solution {
+        Ok(Solution {
+            input_hash: Some(hash),
+            ..
+        }) => hash.to_string(),
+        _ => String::new(),
+    };
+
+    print_table(
+        &["Input", "Hash"],
+        results
+            .iter()
+            .map(|r| (vec![name(r), hash(r)], &r.solution))
+            .collect(),
+    );
+
+    let (solved, total) = total_time(results.iter().map(|r| &r.solution));
+    println!(
+        "\n{solved} of {} inputs solved in {}",
+        results.len(),
+        format_duration(total)
+    );
+
+    for result in results {
+        if let Err(e) = &result.solution {
+            println!("error: {}: {e}", name(result));
+        }
+    }
+}
+
+#[derive(Debug, Serialize)]
+struct JsonTimings {
+    parse_ns: u64,
+    part1_ns: u64,
+    part2_ns: u64,
+    total_ns: u64,
+}
+
+#[derive(Debug, Serialize)]
+struct JsonAllocStats {
+    allocations: u64,
+    bytes: u64,
+    peak_bytes: u64,
+}
+
+impl From<&AllocStats> for JsonAllocStats {
+    fn from(stats: &AllocStats) -> Self {
+        JsonAllocStats {
+            allocations: stats.allocations,
+            bytes: stats.bytes,
+            peak_bytes: stats.peak,
+        }
+    }
+}
+
+#[derive(Debug, Serialize)]
+struct JsonMemory {
+    parse: JsonAllocStats,
+    part1: JsonAllocStats,
+    part2: JsonAllocStats,
+}
+
+#[derive(Debug, Serialize)]
+struct JsonDay {
+    year: i32,
+    day: i32,
+    status: &'static str,
+    part1: Value,
+    part2: Value,
+    timings: Option<JsonTimings>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    memory: Option<JsonMemory>,
+    input_hash: Option<String>,
+    cached: bool,
+    error: Option<String>,
+}
+
+fn answer_json(answer: &Answer) -> Value {
+    match answer {
+        Answer::Number(n) => match i64::try_from(*n) {
+            Ok(n) => Value::from(n),
+            Err(_) => Value::from(n.to_string()),
+        },
+        Answer::Text(s) => Value::from(s.as_str()),
+        Answer::Unsolved => Value::Null,
+    }
+}
+
+fn as_ns(duration: Duration) -> u64 {
+    duration.as_nanos() as u64
+}
+
+fn json_day(result: &DayResult) -> JsonDay {
+    match &result.solution {
+        Ok(solution) => JsonDay {
+            year: result.year,
+            day: result.day.get(),
+            status: "ok",
+            part1: answer_json(&solution.part1),
+            part2: answer_json(&solution.part2),
+            timings: Some(JsonTimings {
+                parse_ns: as_ns(solution.timings.parse),
+                part1_ns: as_ns(solution.timings.part1),
+                part2_ns: as_ns(solution.timings.part2),
+                total_ns: as_ns(solution.timings.total()),
+            }),
+            memory: alloc::ENABLED.then(|| JsonMemory {
+                parse: (&solution.memory.parse).into(),
+                part1: (&solution.memory.part1).into(),
+                part2: (&solution.memory.part2).into(),
+            }),
+            input_hash: solution.input_hash.map(|hash| hash.to_string()),
+            cached: solution.cached,
+            error: None,
+        },
+        Err(e) => JsonDay {
+            year: result.year,
+            day: result.day.get(),
+            status: match e {
+                Error::Timeout(_) => "timeout",
+                Error::Panic(_) => "panic",
+                _ => "error",
+            },
+            part1: Value::Null,
+            part2: Value::Null,
+            timings: None,
+            memory: None,
+            input_hash: None,
+            cached: false,
+            error: Some(e.to_string()),
+        },
+    }
+}
+
+pub fn to_json(results: &[DayResult]) -> String {
+    let days = results.iter().map(json_day).collect::<Vec<JsonDay>>();
+    serde_json::to_string_pretty(&days).expect("JSON serialization of results cannot fail")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use advent_of_code::library::text::InputHash;
+    use advent_of_code::years::Timings;
+
+    #[test]
+    fn test_format_duration() {
+        assert_eq!(format_duration(Duration::from_nanos(1_500)), "1.5µs");
+        assert_eq!(format_duration(Duration::from_micros(12_345)), "12.35ms");
+        assert_eq!(format_duration(Duration::from_millis(2_500)), "2.500s");
+    }
+
+    #[test]
+    fn test_format_bytes() {
+        assert_eq!(format_bytes(512), "512B");
+        assert_eq!(format_bytes(1536), "1.5KiB");
+        assert_eq!(format_bytes(3 * 1024 * 1024), "3.0MiB");
+    }
+
+    #[test]
+    fn test_to_json() {
+        let results = [
+            DayResult {
+                year: 2024,
+                day: DayNum::new(17).unwrap(),
+                solution: Ok(Solution {
+                    part1: Answer::Text("4,6,3".to_string()),
+                    part2: Answer::Number(117440),
+                    timings: Timings {
+                        parse: Duration::from_nanos(10),
+                        part1: Duration::from_nanos(20),
+                        part2: Duration::from_nanos(30),
+                    },
+                    memory: Default::default(),
+                    input_hash: Some(InputHash::of(b"1,2,3")),
+                    cached: false,
+                }),
+            },
+            DayResult {
+                year: 2025,
+                day: DayNum::new(8).unwrap(),
+                solution: Err(Error::parse(0, "expected '<x>,<y>,<z>'")),
+            },
+            DayResult {
+                year: 2024,
+                day: DayNum::new(14).unwrap(),
+                solution: Err(Error::Timeout(Duration::from_secs(5))),
+            },
+        ];
+
+        let json: Value = serde_json::from_str(&to_json(&results)).unwrap();
+        assert_eq!(json[0]["status"], "ok");
+        assert_eq!(json[0]["part1"], "4,6,3");
+        assert_eq!(json[0]["part2"], 117440);
+        assert_eq!(json[0]["timings"]["total_ns"], 60);
+        assert_eq!(json[0]["input_hash"], InputHash::of(b"1,2,3").to_string());
+        assert_eq!(json[1]["input_hash"], Value::Null);
+        assert_eq!(json[1]["status"], "error");
+        assert_eq!(json[1]["part1"], Value::Null);
+        assert_eq!(json[1]["error"], "line 1: expected '<x>,<y>,<z>'");
+        assert_eq!(json[2]["status"], "timeout");
+        assert_eq!(json[2]["error"], "timed out after 5s");
+    }
+}
@@ -0,0 +1,194 @@
+use advent_of_code::error::{Error, Result};
+use advent_of_code::years::DayNum;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+pub const YEARS_DIR: &str = "src/years";
+
+const DAY_TEMPLATE: &str = r##"use crate::error::Result;
+use crate::library::text::InputText;
+use crate::years::{AdventDay, Context, Example, Solution, Solver};
+
+pub struct Day__DAY__ {}
+
+impl AdventDay for Day__DAY__ {
+    fn solve_input(&self, input: &InputText, ctx: &Context) -> Result<Solution> {
+        Solver::new(ctx, input)
+            .part1(|input| part1(input))
+            .part2(|input| part2(input))
+    }
+
+    fn get_input_path(&self) -> &str {
+        "Inputs/__YEAR__/day__DAY__.txt"
+    }
+
+    fn examples(&self) -> &'static [Example] {
+        EXAMPLES
+    }
+}
+
+fn part1(_input: &InputText) -> u64 {
+    0
+}
+
+fn part2(_input: &InputText) -> u64 {
+    0
+}
+
+const EXAMPLE: &str = r#""#;
+
+const EXAMPLES: &[Example] = &[Example {
+    name: "example",
+    input: EXAMPLE,
+    part1: None,
+    part2: None,
+    params: &[],
+}];
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn get_input() -> InputText {
+        InputText::from(EXAMPLE)
+    }
+
+    #[test]
+    fn test_part1() -> Result<(), String> {
+        let input = get_input();
+        assert_eq!(part1(&input), 0);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_part2() -> Result<(), String> {
+        let input = get_input();
+        assert_eq!(part2(&input), 0);
+
+        Ok(())
+    }
+}
+"##;
+
+pub fn day_source(year: i32, day: DayNum) -> String {
+    DAY_TEMPLATE
+        .replace("__YEAR__", &year.to_string())
+        .replace("__DAY__", &day.to_string())
+}
+
+// Adds `day => dayN::DayN,` to the `days!` invocation of a year's mod.rs,
+// keeping the entries sorted by day.
+pub fn register_day(mod_source: &str, day: DayNum) -> Result<String, String> {
+    let start = mod_source
+        .find("days! {\n")
+        .ok_or("could not find the days! macro")?
+        + "days! {\n".len();
+    let end = start
+        + mod_source[start..]
+            .find("}\n")
+            .ok_or("could not find the end of the days! macro")?;
+
+    let mut insert_at = end;
+    let mut offset = start;
+    for line in mod_source[start..end].split_inclusive('\n') {
+        let registered = line
+            .split_once("=>")
+            .and_then(|(num, _)| num.trim().parse::<i32>().ok())
+            .ok_or_else(|| format!("unexpected line in the days! macro: '{}'", line.trim()))?;
+
+        if registered == day.get() {
+            return Err(format!("day {day} is already registered"));
+        }
+        if registered > day.get() {
+            insert_at = offset;
+            break;
+        }
+        offset += line.len();
+    }
+
+    Ok(format!(
+        "{}    {day} => day{day}::Day{day},\n{}",
+        &mod_source[..insert_at],
+        &mod_source[insert_at..]
+    ))
+}
+
+pub fn create_day(years_dir: &Path, year: i32, day: DayNum) -> Result<PathBuf> {
+    let year_dir = years_dir.join(format!("year_{year}"));
+    let mod_path = year_dir.join("mod.rs");
+    let day_path = year_dir.join(format!("day{day}.rs"));
+
+    if day_path.exists() {
+        return Err(Error::usage(format!(
+            "{} already exists",
+            day_path.display()
+        )));
+    }
+
+    let mod_source = fs::read_to_string(&mod_path).map_err(|e| Error::io(&mod_path, e))?;
+    let mod_source = register_day(&mod_source, day).map_err(|e| Error::format(&mod_path, e))?;
+
+    fs::write(&day_path, day_source(year, day)).map_err(|e| Error::io(&day_path, e))?;
+    fs::write(&mod_path, mod_source).map_err(|e| Error::io(&mod_path, e))?;
+    Ok(day_path)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::env;
+
+    const MOD_SOURCE: &str = "use super::{DayNum, RegisteredDay, Year};
+
+days! {
+    1 => day1::Day1,
+    3 => day3::Day3,
+}
+
+pub struct Year2025 {}
+";
+
+    #[test]
+    fn test_day_source() {
+        let source = day_source(2025, DayNum::new(9).unwrap());
+        assert!(source.contains("pub struct Day9 {}"));
+        assert!(source.contains("impl AdventDay for Day9 {"));
+        assert!(source.contains("\"Inputs/2025/day9.txt\""));
+        assert!(!source.contains("__"));
+    }
+
+    #[test]
+    fn test_register_day() {
+        let source = register_day(MOD_SOURCE, DayNum::new(2).unwrap()).unwrap();
+        assert!(source.contains(
+            "days! {\n    1 => day1::Day1,\n    2 => day2::Day2,\n    3 => day3::Day3,\n}\n"
+        ));
+
+        let source = register_day(MOD_SOURCE, DayNum::new(12).unwrap()).unwrap();
+        assert!(source.contains("    3 => day3::Day3,\n    12 => day12::Day12,\n}\n"));
+
+        assert_eq!(
+            register_day(MOD_SOURCE, DayNum::new(3).unwrap()),
+            Err("day 3 is already registered".to_string())
+        );
+    }
+
+    #[test]
+    fn test_create_day() {
+        let dir = env::temp_dir().join(format!("aoc-new-{}", std::process::id()));
+        fs::create_dir_all(dir.join("year_2025")).unwrap();
+        fs::write(dir.join("year_2025").join("mod.rs"), MOD_SOURCE).unwrap();
+
+        let day = DayNum::new(9).unwrap();
+        let path = create_day(&dir, 2025, day).unwrap();
+        assert_eq!(fs::read_to_string(&path).unwrap(), day_source(2025, day));
+        let mod_source = fs::read_to_string(dir.join("year_2025").join("mod.rs")).unwrap();
+        assert!(mod_source.contains("    9 => day9::Day9,\n}"));
+
+        assert!(create_day(&dir, 2025, day).is_err());
+        assert!(create_day(&dir, 2015, day).is_err());
+
+        fs::remove_dir_all(dir).unwrap();
+    }
+}
@@ -0,0 +1,216 @@
+use super::answers::Verdict;
+use super::args::{Options, parse_day, parse_year};
+use super::cache;
+use super::input::{self, InputSource};
+use super::pool;
+use super::report::{self, DayResult, Format, InputResult};
+use advent_of_code::error::{Error, Result};
+use advent_of_code::years::{self, AdventDay, Cancel, Context, DayNum, Example, Solution};
+use std::path::Path;
+use std::process;
+use std::time::Instant;
+
+fn select_days(args: &[String]) -> Result<Vec<(i32, DayNum, &'static dyn AdventDay)>> {
+    let years = match args.first().map(|arg| arg.as_str()) {
+        None | Some("all") => years::YEARS.to_vec(),
+        Some(arg) => vec![parse_year(arg)?],
+    };
+
+    let single_year = years.len() == 1;
+    let mut selected = Vec::new();
+    for year in years {
+        if let Some(arg) = args.get(1) {
+            let day = parse_day(year, arg).and_then(|day| Ok((day, year.find_day(day)?)));
+            match day {
+                Ok((day, advent_day)) => selected.push((year.year(), day, advent_day)),
+                Err(e) if single_year => return Err(e),
+                Err(e) => eprintln!("{e}"),
+            }
+        } else {
+            for &(day, advent_day) in year.days() {
+                selected.push((year.year(), day, advent_day));
+            }
+        }
+    }
+
+    Ok(selected)
+}
+
+pub fn select_days_with_input(
+    args: &[String],
+    opts: &Options,
+) -> Result<Vec<(i32, DayNum, &'static dyn AdventDay)>> {
+    let selected = select_days(args)?;
+    if opts.input != InputSource::Default && selected.len() > 1 {
+        return Err(Error::usage(
+            "--input and --example can only be used when solving a single day",
+        ));
+    }
+
+    Ok(selected)
+}
+
+pub fn solve_day(
+    year: i32,
+    day: DayNum,
+    advent_day: &'static dyn AdventDay,
+    opts: &Options,
+) -> Result<Solution> {
+    solve_input(year, day, advent_day, &opts.input, opts)
+}
+
+fn solve_input(
+    year: i32,
+    day: DayNum,
+    advent_day: &'static dyn AdventDay,
+    input: &InputSource,
+    opts: &Options,
+) -> Result<Solution> {
+    let input = input.clone();
+    let ctx = Context {
+        cancel: Cancel::default(),
+        ..opts.ctx.clone()
+    };
+    let cancel = ctx.cancel.clone();
+    let cache = opts.cache.clone().filter(|_| ctx.params.is_empty());
+    let solve = move || {
+        let input = input.read(advent_day)?;
+        input.validate(year, day, advent_day)?;
+
+        let Some(cache) = cache.filter(|_| input.params.is_empty()) else {
+            return input.solve(advent_day, &ctx);
+        };
+        let key = cache::Key {
+            year,
+            day,
+            input: input.text.hash(),
+        };
+        if let Some(solution) = cache.get(&key, ctx.part) {
+            return Ok(solution);
+        }
+        let solution = input.solve(advent_day, &ctx)?;
+        // Never cache the answers of a day that was given up on
+        ctx.cancel.check()?;
+        cache.put(&key, ctx.part, &solution)?;
+        Ok(solution)
+    };
+
+    pool::catch_panic(|| match opts.timeout {
+        Some(timeout) => pool::run_with_timeout(timeout, solve).unwrap_or_else(|| {
+            cancel.cancel(timeout);
+            Err(Error::Timeout(timeout))
+        }),
+        None => solve(),
+    })
+    .unwrap_or_else(|message| Err(Error::Panic(message)))
+}
+
+pub fn solve_days(args: &[String], opts: &Options) -> Result<()> {
+    let selected = select_days_with_input(args, opts)?;
+    let start = Instant::now();
+    let results = pool::map(&selected, opts.jobs, |&(year, day, advent_day)| DayResult {
+        year,
+        day,
+        solution: solve_day(year, day, advent_day, opts),
+    });
+    let wall_clock = start.elapsed();
+
+    match opts.format {
+        Format::Text => {
+            report::print_summary(&results, wall_clock, opts.jobs, pool::still_running())
+        }
+        Format::Json => println!("{}", report::to_json(&results)),
+    }
+
+    if results.iter().any(|result| result.solution.is_err()) {
+        process::exit(1);
+    }
+    Ok(())
+}
+
+pub fn solve_single_day(args: &[String], opts: &Options) -> Result<()> {
+    let year = parse_year(&args[0])?;
+    let day = parse_day(year, &args[1])?;
+    let advent_day = year.find_day(day)?;
+
+    let year = year.year();
+
+    if let Some(dir) = &opts.inputs_dir {
+        return solve_inputs_dir(year, day, advent_day, dir, opts);
+    }
+
+    if opts.format == Format::Json {
+        let result = DayResult {
+            year,
+            day,
+            solution: solve_day(year, day, advent_day, opts),
+        };
+        println!("{}", report::to_json(std::slice::from_ref(&result)));
+        if result.solution.is_err() {
+            process::exit(1);
+        }
+        return Ok(());
+    }
+
+    let solution = solve_day(year, day, advent_day, opts)?;
+    report::print_solution(&solution);
+
+    if let InputSource::Example(name) = &opts.input
+        && opts.ctx.params.is_empty()
+        && let Some(example) = advent_day.get_example(name)
+        && !check_example(example, &solution, opts.ctx.part)
+    {
+        process::exit(1);
+    }
+    Ok(())
+}
+
+fn solve_inputs_dir(
+    year: i32,
+    day: DayNum,
+    advent_day: &'static dyn AdventDay,
+    dir: &Path,
+    opts: &Options,
+) -> Result<()> {
+    let paths = input::list_dir(dir)?;
+    let results = pool::map(&paths, opts.jobs, |path| InputResult {
+        path: path.clone(),
+        solution: solve_input(
+            year,
+            day,
+            advent_day,
+            &InputSource::File(path.display().to_string()),
+            opts,
+        ),
+    });
+
+    report::print_input_results(&results);
+    if results.iter().any(|result| result.solution.is_err()) {
+        process::exit(1);
+    }
+    Ok(())
+}
+
+fn check_example(example: &Example, solution: &Solution, only_part: Option<usize>) -> bool {
+    let mut passed = true;
+    for (part, answer) in [(1, &solution.part1), (2, &solution.part2)] {
+        if only_part.is_some_and(|only_part| only_part != part) {
+            continue;
+        }
+        let Some(expected) = example.expected(part) else {
+            continue;
+        };
+
+        if expected == answer.to_string() {
+            println!("Example part{part}: {}", Verdict::Pass);
+        } else {
+            println!(
+                "Example part{part}: {} (expected {expected})",
+                Verdict::Fail
+            );
+            passed = false;
+        }
+    }
+
+    passed
+}
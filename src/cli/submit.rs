@@ -0,0 +1,238 @@
+use advent_of_code::error::{Error, Result};
+use advent_of_code::years::{Answer, DayNum};
+use serde::{Deserialize, Serialize};
+use std::fmt;
+use std::fs;
+use std::io::ErrorKind;
+use std::time::Duration;
+
+pub const GUESSES_PATH: &str = "guesses.toml";
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Hint {
+    TooHigh,
+    TooLow,
+}
+
+impl fmt::Display for Hint {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Hint::TooHigh => write!(f, "too high"),
+            Hint::TooLow => write!(f, "too low"),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Outcome {
+    Correct,
+    Wrong(Option<Hint>),
+    RateLimited(Option<Duration>),
+    AlreadyCompleted,
+    Unknown,
+}
+
+impl fmt::Display for Outcome {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Outcome::Correct => write!(f, "That's the right answer!"),
+            Outcome::Wrong(Some(hint)) => write!(f, "That's not the right answer, it is {hint}"),
+            Outcome::Wrong(None) => write!(f, "That's not the right answer"),
+            Outcome::RateLimited(Some(wait)) => write!(
+                f,
+                "You gave an answer too recently, wait {}m {}s",
+                wait.as_secs() / 60,
+                wait.as_secs() % 60
+            ),
+            Outcome::RateLimited(None) => write!(f, "You gave an answer too recently"),
+            Outcome::AlreadyCompleted => {
+                write!(f, "This part is already completed or still locked")
+            }
+            Outcome::Unknown => write!(f, "Could not understand the response of the server"),
+        }
+    }
+}
+
+pub fn parse_response(html: &str) -> Outcome {
+    if html.contains("That's the right answer") {
+        Outcome::Correct
+    } else if html.contains("That's not the right answer") {
+        let hint = if html.contains("your answer is too high") {
+            Some(Hint::TooHigh)
+        } else if html.contains("your answer is too low") {
+            Some(Hint::TooLow)
+        } else {
+            None
+        };
+        Outcome::Wrong(hint)
+    } else if html.contains("You gave an answer too recently") {
+        Outcome::RateLimited(parse_wait(html))
+    } else if html.contains("You don't seem to be solving the right level") {
+        Outcome::AlreadyCompleted
+    } else {
+        Outcome::Unknown
+    }
+}
+
+// Parses "You have 4m 30s left to wait." into the remaining duration.
+fn parse_wait(html: &str) -> Option<Duration> {
+    let start = html.find("You have ")? + "You have ".len();
+    let end = start + html[start..].find(" left to wait")?;
+
+    let mut seconds = 0;
+    for part in html[start..end].split_ascii_whitespace() {
+        let (value, unit) = part.split_at(part.find(|c: char| !c.is_ascii_digit())?);
+        let value = value.parse::<u64>().ok()?;
+        seconds += match unit {
+            "m" => value * 60,
+            "s" => value,
+            _ => return None,
+        };
+    }
+
+    Some(Duration::from_secs(seconds))
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Guess {
+    pub year: i32,
+    pub day: i32,
+    pub part: usize,
+    pub answer: String,
+    pub hint: Option<Hint>,
+}
+
+impl Guess {
+    fn rules_out(&self, answer: &Answer) -> bool {
+        if self.answer == answer.to_string() {
+            return true;
+        }
+
+        let (Answer::Number(answer), Ok(guess)) = (answer, self.answer.parse::<i128>()) else {
+            return false;
+        };
+        match self.hint {
+            Some(Hint::TooHigh) => *answer >= guess,
+            Some(Hint::TooLow) => *answer <= guess,
+            None => false,
+        }
+    }
+}
+
+#[derive(Debug, Default, Serialize, Deserialize)]
+pub struct Guesses {
+    #[serde(default, rename = "guess")]
+    guesses: Vec<Guess>,
+}
+
+impl Guesses {
+    pub fn load(path: &str) -> Result<Guesses> {
+        match fs::read_to_string(path) {
+            Ok(content) => toml::from_str(&content).map_err(|e| Error::format(path, e)),
+            Err(e) if e.kind() == ErrorKind::NotFound => Ok(Guesses::default()),
+            Err(e) => Err(Error::io(path, e)),
+        }
+    }
+
+    pub fn save(&self, path: &str) -> Result<()> {
+        let content = toml::to_string(self).map_err(|e| Error::format(path, e))?;
+        fs::write(path, content).map_err(|e| Error::io(path, e))
+    }
+
+    pub fn find(&self, year: i32, day: DayNum, part: usize, answer: &Answer) -> Option<&Guess> {
+        self.guesses.iter().find(|guess| {
+            guess.year == year
+                && guess.day == day.get()
+                && guess.part == part
+                && guess.rules_out(answer)
+        })
+    }
+
+    pub fn add(
+        &mut self,
+        year: i32,
+        day: DayNum,
+        part: usize,
+        answer: &Answer,
+        hint: Option<Hint>,
+    ) {
+        self.guesses.push(Guess {
+            year,
+            day: day.get(),
+            part,
+            answer: answer.to_string(),
+            hint,
+        });
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_load() {
+        let dir = std::env::temp_dir().join(format!("aoc-guesses-{}", std::process::id()));
+        fs::create_dir_all(&dir).unwrap();
+
+        let missing = dir.join("guesses.toml");
+        assert!(Guesses::load(missing.to_str().unwrap()).is_ok());
+
+        let error = Guesses::load(dir.to_str().unwrap()).err().unwrap();
+        assert!(matches!(error, Error::Io { .. }));
+
+        fs::remove_dir_all(dir).unwrap();
+    }
+
+    #[test]
+    fn test_parse_response() {
+        assert_eq!(
+            parse_response("<p>That's the right answer! You are one gold star closer.</p>"),
+            Outcome::Correct
+        );
+        assert_eq!(
+            parse_response(
+                "<p>That's not the right answer; your answer is too high. Please wait one minute before trying again.</p>"
+            ),
+            Outcome::Wrong(Some(Hint::TooHigh))
+        );
+        assert_eq!(
+            parse_response("<p>That's not the right answer. If you're stuck...</p>"),
+            Outcome::Wrong(None)
+        );
+        assert_eq!(
+            parse_response("<p>You gave an answer too recently. You have 4m 30s left to wait.</p>"),
+            Outcome::RateLimited(Some(Duration::from_secs(270)))
+        );
+        assert_eq!(
+            parse_response("<p>You gave an answer too recently. You have 45s left to wait.</p>"),
+            Outcome::RateLimited(Some(Duration::from_secs(45)))
+        );
+        assert_eq!(
+            parse_response(
+                "<p>You don't seem to be solving the right level.  Did you already complete it?</p>"
+            ),
+            Outcome::AlreadyCompleted
+        );
+        assert_eq!(parse_response("<html></html>"), Outcome::Unknown);
+    }
+
+    #[test]
+    fn test_guesses() {
+        let day = DayNum::new(17).unwrap();
+        let mut guesses = Guesses::default();
+        guesses.add(2024, day, 1, &Answer::Number(500), Some(Hint::TooHigh));
+        guesses.add(2024, day, 1, &Answer::Number(100), Some(Hint::TooLow));
+        guesses.add(2024, day, 2, &Answer::from("1,2,3"), None);
+
+        let guesses: Guesses = toml::from_str(&toml::to_string(&guesses).unwrap()).unwrap();
+        assert!(guesses.find(2024, day, 1, &Answer::Number(500)).is_some());
+        assert!(guesses.find(2024, day, 1, &Answer::Number(600)).is_some());
+        assert!(guesses.find(2024, day, 1, &Answer::Number(50)).is_some());
+        assert!(guesses.find(2024, day, 1, &Answer::Number(250)).is_none());
+        assert!(guesses.find(2024, day, 2, &Answer::from("1,2,3")).is_some());
+        assert!(guesses.find(2024, day, 2, &Answer::from("1,2")).is_none());
+        assert!(guesses.find(2023, day, 1, &Answer::Number(500)).is_none());
+    }
+}
@@ -0,0 +1,210 @@
+use std::fmt;
+use std::io;
+use std::path::{Path, PathBuf};
+use std::time::Duration;
+
+#[derive(Debug)]
+pub enum Error {
+    Io {
+        path: PathBuf,
+        source: io::Error,
+    },
+    Parse {
+        file: Option<PathBuf>,
+        line: usize,
+        column: Option<usize>,
+        message: String,
+    },
+    Mismatch {
+        file: Option<PathBuf>,
+        expected: String,
+        reason: Box<Error>,
+    },
+    Format {
+        path: PathBuf,
+        message: String,
+    },
+    Http {
+        url: String,
+        message: String,
+    },
+    Usage(String),
+    Timeout(Duration),
+    Panic(String),
+}
+
+pub type Result<T, E = Error> = std::result::Result<T, E>;
+
+impl Error {
+    pub fn parse(line_idx: usize, message: impl Into<String>) -> Error {
+        Error::Parse {
+            file: None,
+            line: line_idx + 1,
+            column: None,
+            message: message.into(),
+        }
+    }
+
+    pub fn parse_at(line_idx: usize, column_idx: usize, message: impl Into<String>) -> Error {
+        Error::Parse {
+            file: None,
+            line: line_idx + 1,
+            column: Some(column_idx + 1),
+            message: message.into(),
+        }
+    }
+
+    // The input failed the checks of the day it was given to, `expected`
+    // names that day, e.g. "2024 day 16".
+    pub fn mismatch(expected: impl Into<String>, reason: Error) -> Error {
+        Error::Mismatch {
+            file: None,
+            expected: expected.into(),
+            reason: Box::new(reason),
+        }
+    }
+
+    pub fn format(path: impl AsRef<Path>, message: impl fmt::Display) -> Error {
+        Error::Format {
+            path: path.as_ref().to_path_buf(),
+            message: message.to_string(),
+        }
+    }
+
+    pub fn io(path: impl AsRef<Path>, source: io::Error) -> Error {
+        Error::Io {
+            path: path.as_ref().to_path_buf(),
+            source,
+        }
+    }
+
+    pub fn http(url: &str, message: impl fmt::Display) -> Error {
+        Error::Http {
+            url: url.to_string(),
+            message: message.to_string(),
+        }
+    }
+
+    pub fn usage(message: impl Into<String>) -> Error {
+        Error::Usage(message.into())
+    }
+
+    pub fn with_file(self, path: &Path) -> Error {
+        match self {
+            Error::Parse {
+                file: None,
+                line,
+                column,
+                message,
+            } => Error::Parse {
+                file: Some(path.to_path_buf()),
+                line,
+                column,
+                message,
+            },
+            Error::Mismatch {
+                file: None,
+                expected,
+                reason,
+            } => Error::Mismatch {
+                file: Some(path.to_path_buf()),
+                expected,
+                reason,
+            },
+            error => error,
+        }
+    }
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Error::Io { path, source } => write!(f, "could not read {}: {source}", path.display()),
+            Error::Parse {
+                file,
+                line,
+                column,
+                message,
+            } => {
+                if let Some(file) = file {
+                    write!(f, "{}: ", file.display())?;
+                }
+                write!(f, "line {line}")?;
+                if let Some(column) = column {
+                    write!(f, ", column {column}")?;
+                }
+                write!(f, ": {message}")
+            }
+            Error::Mismatch {
+                file,
+                expected,
+                reason,
+            } => {
+                if let Some(file) = file {
+                    write!(f, "{}: ", file.display())?;
+                }
+                write!(f, "this doesn't look like a {expected} input ({reason})")
+            }
+            Error::Format { path, message } => {
+                write!(f, "could not parse {}: {message}", path.display())
+            }
+            Error::Http { url, message } => write!(f, "request to {url} failed: {message}"),
+            Error::Usage(message) => write!(f, "{message}"),
+            Error::Panic(message) => write!(f, "panicked: {message}"),
+            Error::Timeout(limit) => write!(f, "timed out after {}s", limit.as_secs_f64()),
+        }
+    }
+}
+
+impl std::error::Error for Error {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Error::Io { source, .. } => Some(source),
+            _ => None,
+        }
+    }
+}
+
+// Byte offset of `part` within `line`, for pointing at the column of a
+// sub-slice that was produced by splitting `line`.
+pub fn offset_in(line: &str, part: &str) -> usize {
+    (part.as_ptr() as usize)
+        .checked_sub(line.as_ptr() as usize)
+        .filter(|offset| *offset <= line.len())
+        .unwrap_or(0)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_display() {
+        let error = Error::parse(11, "expected 'Register A: <n>'");
+        assert_eq!(error.to_string(), "line 12: expected 'Register A: <n>'");
+
+        let error = Error::parse_at(0, 4, "unknown gate type 'NAND'")
+            .with_file(Path::new("Inputs/2024/day24.txt"));
+        assert_eq!(
+            error.to_string(),
+            "Inputs/2024/day24.txt: line 1, column 5: unknown gate type 'NAND'"
+        );
+    }
+
+    #[test]
+    fn test_mismatch() {
+        let reason = Error::parse_at(0, 1, "unexpected character '|'");
+        let error = Error::mismatch("2024 day 16", reason).with_file(Path::new("day16.txt"));
+        assert_eq!(
+            error.to_string(),
+            "day16.txt: this doesn't look like a 2024 day 16 input (line 1, column 2: unexpected character '|')"
+        );
+    }
+
+    #[test]
+    fn test_offset_in() {
+        let line = "x00 AND y00 -> z00";
+        let op = line.split_ascii_whitespace().nth(1).unwrap();
+        assert_eq!(offset_in(line, op), 4);
+    }
+}
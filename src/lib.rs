@@ -0,0 +1,5 @@
+pub mod alloc;
+pub mod error;
+pub mod library;
+pub mod log;
+pub mod years;
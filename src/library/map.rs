@@ -1,5 +1,7 @@
 use super::dir::*;
 use super::pos::*;
+use crate::log;
+use std::convert::Infallible;
 
 pub struct Map<T> {
     pub map: Vec<T>,
@@ -9,32 +11,43 @@ pub struct Map<T> {
 }
 
 impl<T> Map<T> {
-    pub fn new<F>(lines: &[String], mut func: F) -> Map<T>
+    pub fn new<S, F>(lines: &[S], mut func: F) -> Map<T>
     where
+        S: AsRef<str>,
         F: FnMut(char, &Pos) -> T,
+    {
+        match Map::try_new(lines, |c, pos| Ok::<T, Infallible>(func(c, pos))) {
+            Ok(map) => map,
+        }
+    }
+
+    pub fn try_new<S, F, E>(lines: &[S], mut func: F) -> Result<Map<T>, E>
+    where
+        S: AsRef<str>,
+        F: FnMut(char, &Pos) -> Result<T, E>,
     {
         let size_y = lines.len();
-        let size_x = lines[0].len();
+        let size_x = lines.first().map_or(0, |line| line.as_ref().len());
         let mut map = Vec::with_capacity(size_y * size_x);
 
         for (row, line) in lines.iter().enumerate() {
-            for (col, char) in line.chars().enumerate() {
+            for (col, char) in line.as_ref().chars().enumerate() {
                 map.push(func(
                     char,
                     &Pos {
                         x: col as i32,
                         y: row as i32,
                     },
-                ));
+                )?);
             }
         }
 
-        Map {
+        Ok(Map {
             map,
             size_x,
             size_y,
             transposed: false,
-        }
+        })
     }
 
     pub fn rows(&self) -> usize {
@@ -108,16 +121,13 @@ impl<T> Map<T> {
         self.transposed = !self.transposed;
     }
 
-    pub fn print<F>(&self, mut func: F)
+    pub fn render<F>(&self, mut func: F) -> String
     where
         F: FnMut(&T) -> char,
     {
-        for y in 0..self.rows() as i32 {
-            for x in 0..self.cols() as i32 {
-                print!("{}", func(self.get(&Pos { x, y })));
-            }
-            println!()
-        }
+        log::render_grid(self.cols() as i32, self.rows() as i32, |pos| {
+            func(self.get(&pos))
+        })
     }
 }
 
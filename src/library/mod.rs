@@ -1,14 +1,18 @@
 pub mod dir;
 pub mod map;
+pub mod parse;
 pub mod pos;
 pub mod pos3d;
 pub mod prime;
+pub mod text;
+pub mod validate;
 
 use std::fs;
 use std::io::Error;
 use std::ops::{Add, Div, Mul, Sub};
+use std::path::Path;
 
-pub fn parse_file(file: &str) -> Result<String, Error> {
+pub fn parse_file(file: impl AsRef<Path>) -> Result<String, Error> {
     fs::read_to_string(file)
 }
 
@@ -0,0 +1,182 @@
+use super::text::{InputText, Section};
+use crate::error::{Error, Result, offset_in};
+use std::str::FromStr;
+
+pub fn split_sections<const N: usize>(input: &InputText) -> Result<[Section<'_>; N]> {
+    let sections = input.sections().collect::<Vec<Section>>();
+    let found = sections.len();
+    let line_idx = sections
+        .get(N)
+        .map(|section| section.start)
+        .unwrap_or(input.lines().count().saturating_sub(1));
+
+    sections.try_into().map_err(|_| {
+        Error::parse(
+            line_idx,
+            format!("expected {N} sections separated by blank lines, found {found}"),
+        )
+    })
+}
+
+pub fn parse_number<T: FromStr>(line: &str, field: &str, line_idx: usize) -> Result<T> {
+    field.parse().map_err(|_| {
+        Error::parse_at(
+            line_idx,
+            offset_in(line, field),
+            format!("expected a number, got '{field}'"),
+        )
+    })
+}
+
+// All integers in `line`. A '-' directly in front of a number makes it
+// negative unless it follows a digit, so "3-5" reads as a range of 3 and 5.
+pub fn signed_integers<T: FromStr>(line: &str, line_idx: usize) -> Result<Vec<T>> {
+    let bytes = line.as_bytes();
+    let mut numbers = Vec::new();
+    let mut idx = 0;
+
+    while idx < bytes.len() {
+        let negative = bytes[idx] == b'-'
+            && bytes.get(idx + 1).is_some_and(u8::is_ascii_digit)
+            && (idx == 0 || !bytes[idx - 1].is_ascii_digit());
+        if !negative && !bytes[idx].is_ascii_digit() {
+            idx += 1;
+            continue;
+        }
+
+        let start = idx;
+        idx += 1;
+        while idx < bytes.len() && bytes[idx].is_ascii_digit() {
+            idx += 1;
+        }
+        numbers.push(parse_number(line, &line[start..idx], line_idx)?);
+    }
+
+    Ok(numbers)
+}
+
+pub fn key_value(line: &str, line_idx: usize) -> Result<(&str, &str)> {
+    line.split_once(':')
+        .map(|(key, value)| (key.trim(), value.trim()))
+        .filter(|(key, _)| !key.is_empty())
+        .ok_or_else(|| Error::parse(line_idx, "expected '<key>: <value>'"))
+}
+
+struct Field<'a> {
+    line_idx: usize,
+    line: &'a str,
+    key: &'a str,
+    value: &'a str,
+}
+
+// A section of "key: value" lines whose values are parsed on access.
+pub struct Record<'a> {
+    start: usize,
+    fields: Vec<Field<'a>>,
+}
+
+impl<'a> Record<'a> {
+    pub fn parse(section: Section<'a>) -> Result<Record<'a>> {
+        let fields = section
+            .indexed()
+            .map(|(line_idx, line)| {
+                let (key, value) = key_value(line, line_idx)?;
+                Ok(Field {
+                    line_idx,
+                    line,
+                    key,
+                    value,
+                })
+            })
+            .collect::<Result<Vec<Field>>>()?;
+
+        Ok(Record {
+            start: section.start,
+            fields,
+        })
+    }
+
+    fn parse_field<T: FromStr>(field: &Field) -> Result<T> {
+        field.value.parse().map_err(|_| {
+            Error::parse_at(
+                field.line_idx,
+                offset_in(field.line, field.value),
+                format!("invalid value '{}' for '{}'", field.value, field.key),
+            )
+        })
+    }
+
+    pub fn get<T: FromStr>(&self, key: &str) -> Result<T> {
+        let field = self
+            .fields
+            .iter()
+            .find(|field| field.key == key)
+            .ok_or_else(|| Error::parse(self.start, format!("missing '{key}: <value>'")))?;
+        Record::parse_field(field)
+    }
+
+    pub fn values<T: FromStr>(&self) -> Result<Vec<(&'a str, T)>> {
+        self.fields
+            .iter()
+            .map(|field| Ok((field.key, Record::parse_field(field)?)))
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_split_sections() {
+        let input = InputText::from("\n47|53\n97|13\n\n\n75,47\n\n61,13");
+        let error = split_sections::<2>(&input).unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "line 8: expected 2 sections separated by blank lines, found 3"
+        );
+
+        let input = InputText::from("47|53\n97|13\n\n75,47");
+        let [rules, updates] = split_sections(&input).unwrap();
+        assert_eq!((rules.start, updates.start), (0, 3));
+        assert_eq!(updates.text, "75,47");
+    }
+
+    #[test]
+    fn test_signed_integers() {
+        assert_eq!(
+            signed_integers::<i32>("p=0,4 v=3,-3", 0).unwrap(),
+            vec![0, 4, 3, -3]
+        );
+        assert_eq!(signed_integers::<u64>("3-5", 0).unwrap(), vec![3, 5]);
+        assert_eq!(signed_integers::<i32>("no numbers", 0).unwrap(), vec![]);
+
+        let error = signed_integers::<u8>("a=1 b=300", 4).unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "line 5, column 7: expected a number, got '300'"
+        );
+    }
+
+    #[test]
+    fn test_record() {
+        let input = InputText::from("Register A: 729\nRegister B: x\nName: day17");
+        let record = Record::parse(input.sections().next().unwrap()).unwrap();
+        assert_eq!(record.get::<u64>("Register A").unwrap(), 729);
+        assert_eq!(record.get::<String>("Name").unwrap(), "day17");
+        assert_eq!(
+            record.get::<u64>("Register B").unwrap_err().to_string(),
+            "line 2, column 13: invalid value 'x' for 'Register B'"
+        );
+        assert_eq!(
+            record.get::<u64>("Register C").unwrap_err().to_string(),
+            "line 1: missing 'Register C: <value>'"
+        );
+
+        let input = InputText::from("x00: 1\ny00 AND x00");
+        let error = Record::parse(input.sections().next().unwrap())
+            .err()
+            .unwrap();
+        assert_eq!(error.to_string(), "line 2: expected '<key>: <value>'");
+    }
+}
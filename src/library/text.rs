@@ -0,0 +1,203 @@
+use std::fmt;
+
+// Owns the whole puzzle input once and hands out borrowed views of it, so
+// days can parse without allocating a `String` per line.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct InputText {
+    buffer: String,
+}
+
+impl InputText {
+    pub fn new(buffer: String) -> InputText {
+        InputText { buffer }
+    }
+
+    pub fn as_str(&self) -> &str {
+        &self.buffer
+    }
+
+    pub fn bytes(&self) -> &[u8] {
+        self.buffer.as_bytes()
+    }
+
+    pub fn lines(&self) -> impl Iterator<Item = &str> {
+        self.buffer.lines()
+    }
+
+    // Blocks of lines separated by one or more blank lines, without the
+    // trailing line break of their last line.
+    pub fn sections(&self) -> impl Iterator<Item = Section<'_>> {
+        let buffer = self.buffer.as_str();
+        let mut offset = 0;
+        let mut line_idx = 0;
+        let mut start = None;
+        let mut end = 0;
+        let mut lines = buffer.split_inclusive('\n');
+
+        let section = move |(section_start, first_line): (usize, usize), end: usize| Section {
+            start: first_line,
+            text: &buffer[section_start..end],
+        };
+        std::iter::from_fn(move || {
+            for line in lines.by_ref() {
+                let content = line.trim_end_matches(['\n', '\r']);
+                let line_start = offset;
+                offset += line.len();
+                line_idx += 1;
+
+                if content.is_empty() {
+                    if let Some(section_start) = start.take() {
+                        return Some(section(section_start, end));
+                    }
+                } else {
+                    start.get_or_insert((line_start, line_idx - 1));
+                    end = line_start + content.len();
+                }
+            }
+
+            start
+                .take()
+                .map(|section_start| section(section_start, end))
+        })
+    }
+
+    pub fn hash(&self) -> InputHash {
+        InputHash::of(self.bytes())
+    }
+
+    // Adapter for days that still take `&[String]`.
+    pub fn to_lines(&self) -> Vec<String> {
+        self.lines().map(str::to_string).collect()
+    }
+}
+
+// A block of non-blank lines. `start` is the index of its first line in the
+// whole input so errors can point at the right line.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Section<'a> {
+    pub start: usize,
+    pub text: &'a str,
+}
+
+impl<'a> Section<'a> {
+    pub fn lines(&self) -> impl Iterator<Item = &'a str> + use<'a> {
+        self.text.lines()
+    }
+
+    pub fn indexed(&self) -> impl Iterator<Item = (usize, &'a str)> + use<'a> {
+        let start = self.start;
+        self.lines()
+            .enumerate()
+            .map(move |(idx, line)| (start + idx, line))
+    }
+}
+
+// FNV-1a of the raw input bytes. It is not meant to be collision resistant,
+// only short enough to compare inputs at a glance.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct InputHash(u64);
+
+impl InputHash {
+    const OFFSET_BASIS: u64 = 0xcbf2_9ce4_8422_2325;
+    const PRIME: u64 = 0x0100_0000_01b3;
+
+    pub fn of(bytes: &[u8]) -> InputHash {
+        InputHash(bytes.iter().fold(InputHash::OFFSET_BASIS, |hash, &byte| {
+            (hash ^ byte as u64).wrapping_mul(InputHash::PRIME)
+        }))
+    }
+
+    pub fn get(&self) -> u64 {
+        self.0
+    }
+}
+
+impl fmt::Display for InputHash {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{:016x}", self.0)
+    }
+}
+
+impl From<String> for InputText {
+    fn from(buffer: String) -> Self {
+        InputText::new(buffer)
+    }
+}
+
+impl From<&str> for InputText {
+    fn from(buffer: &str) -> Self {
+        InputText::new(buffer.to_string())
+    }
+}
+
+impl From<&[String]> for InputText {
+    fn from(lines: &[String]) -> Self {
+        InputText::new(lines.join("\n"))
+    }
+}
+
+impl fmt::Display for InputText {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.buffer)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_lines() {
+        let input = InputText::from("47|53\r\n97|13\n\n75,47\n");
+        assert_eq!(
+            input.lines().collect::<Vec<&str>>(),
+            vec!["47|53", "97|13", "", "75,47"]
+        );
+        assert_eq!(input.to_lines(), vec!["47|53", "97|13", "", "75,47"]);
+        assert_eq!(input.bytes()[0], b'4');
+    }
+
+    #[test]
+    fn test_sections() {
+        let input = InputText::from("\n47|53\n97|13\n\n\n75,47\r\n\r\n61,13\n");
+        let sections = input.sections().collect::<Vec<Section>>();
+        assert_eq!(
+            sections.iter().map(|s| s.text).collect::<Vec<&str>>(),
+            vec!["47|53\n97|13", "75,47", "61,13"]
+        );
+        assert_eq!(
+            sections.iter().map(|s| s.start).collect::<Vec<usize>>(),
+            vec![1, 5, 7]
+        );
+        assert_eq!(
+            sections[0].indexed().collect::<Vec<_>>(),
+            vec![(1, "47|53"), (2, "97|13")]
+        );
+
+        let input = InputText::from("#.#\n..#");
+        assert_eq!(
+            input.sections().map(|s| s.text).collect::<Vec<&str>>(),
+            vec!["#.#\n..#"]
+        );
+        assert_eq!(InputText::default().sections().count(), 0);
+    }
+
+    #[test]
+    fn test_hash() {
+        assert_eq!(InputHash::of(b"").get(), 0xcbf2_9ce4_8422_2325);
+        assert_eq!(InputHash::of(b"a").to_string(), "af63dc4c8601ec8c");
+        assert_eq!(InputHash::of(b"foobar").to_string(), "85944171f73967e8");
+        assert_ne!(
+            InputText::from("1\n2").hash(),
+            InputText::from("1\n2\n").hash()
+        );
+    }
+
+    #[test]
+    fn test_from_lines() {
+        let lines = vec!["a".to_string(), "b".to_string()];
+        let input = InputText::from(lines.as_slice());
+        assert_eq!(input.as_str(), "a\nb");
+        assert_eq!(input.to_lines(), lines);
+    }
+}
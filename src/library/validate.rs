@@ -0,0 +1,149 @@
+use super::text::InputText;
+use crate::error::{Error, Result};
+use regex::Regex;
+
+// Cheap sanity checks a day can declare about the shape of its input, so a
+// wrong file is rejected before it reaches the parser.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Check {
+    // Every character apart from line breaks is one of these
+    Chars(&'static str),
+    // Every non-blank line matches this regex as a whole
+    Lines(&'static str),
+    // The first section is a rectangle of equally long lines
+    Grid,
+    // Like `Grid`, with as many rows as columns
+    SquareGrid,
+}
+
+fn check_chars(input: &InputText, allowed: &str) -> Result<()> {
+    for (idx, line) in input.lines().enumerate() {
+        if let Some((col, c)) = line.char_indices().find(|(_, c)| !allowed.contains(*c)) {
+            return Err(Error::parse_at(
+                idx,
+                col,
+                format!("unexpected character '{c}'"),
+            ));
+        }
+    }
+    Ok(())
+}
+
+fn check_lines(input: &InputText, pattern: &str) -> Result<()> {
+    let regex = Regex::new(&format!("^(?:{pattern})$"))
+        .unwrap_or_else(|e| panic!("invalid line pattern '{pattern}': {e}"));
+
+    match input
+        .lines()
+        .enumerate()
+        .find(|(_, line)| !line.is_empty() && !regex.is_match(line))
+    {
+        Some((idx, line)) => Err(Error::parse(
+            idx,
+            format!("'{line}' does not match '{pattern}'"),
+        )),
+        None => Ok(()),
+    }
+}
+
+fn check_grid(input: &InputText, square: bool) -> Result<()> {
+    let Some(section) = input.sections().next() else {
+        return Err(Error::parse(0, "expected a grid"));
+    };
+    let mut rows = section.indexed();
+    let Some((first_idx, first)) = rows.next() else {
+        return Err(Error::parse(section.start, "expected a grid"));
+    };
+
+    let width = first.chars().count();
+    let mut height = 1;
+    for (idx, line) in rows {
+        let len = line.chars().count();
+        if len != width {
+            return Err(Error::parse(
+                idx,
+                format!(
+                    "expected {width} columns like line {}, found {len}",
+                    first_idx + 1
+                ),
+            ));
+        }
+        height += 1;
+    }
+
+    if square && height != width {
+        return Err(Error::parse(
+            first_idx,
+            format!("expected a square grid, found {width} columns and {height} rows"),
+        ));
+    }
+    Ok(())
+}
+
+pub fn validate(input: &InputText, checks: &[Check]) -> Result<()> {
+    if !checks.is_empty() && input.sections().next().is_none() {
+        return Err(Error::parse(0, "input is empty"));
+    }
+
+    for check in checks {
+        match check {
+            Check::Chars(allowed) => check_chars(input, allowed)?,
+            Check::Lines(pattern) => check_lines(input, pattern)?,
+            Check::Grid => check_grid(input, false)?,
+            Check::SquareGrid => check_grid(input, true)?,
+        }
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_chars() {
+        let checks = [Check::Chars("#.SE")];
+        assert!(validate(&InputText::from("#S.\n#.E\n"), &checks).is_ok());
+
+        let error = validate(&InputText::from("#S.\n#|E"), &checks).unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "line 2, column 2: unexpected character '|'"
+        );
+    }
+
+    #[test]
+    fn test_lines() {
+        let checks = [Check::Lines(r"\d+\|\d+|\d+(,\d+)*")];
+        assert!(validate(&InputText::from("47|53\n\n75,47,61"), &checks).is_ok());
+
+        let error = validate(&InputText::from("47|53\n\n75;47"), &checks).unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            r"line 3: '75;47' does not match '\d+\|\d+|\d+(,\d+)*'"
+        );
+    }
+
+    #[test]
+    fn test_grid() {
+        let input = InputText::from("###\n#.#\n###\n\n<>^v<");
+        assert!(validate(&input, &[Check::Grid]).is_ok());
+        assert!(validate(&input, &[Check::SquareGrid]).is_ok());
+
+        let error = validate(&InputText::from("####\n#..#\n###"), &[Check::Grid]).unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "line 3: expected 4 columns like line 1, found 3"
+        );
+
+        let error = validate(&InputText::from("##\n##\n##"), &[Check::SquareGrid]).unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "line 1: expected a square grid, found 2 columns and 3 rows"
+        );
+
+        let error = validate(&InputText::from("\n\n"), &[Check::Grid]).unwrap_err();
+        assert_eq!(error.to_string(), "line 1: input is empty");
+        assert!(validate(&InputText::default(), &[]).is_ok());
+    }
+}
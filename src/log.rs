@@ -0,0 +1,109 @@
+use crate::library::pos::Pos;
+use std::fmt;
+use std::sync::atomic::{AtomicU8, Ordering};
+
+// Debug output of the days goes to stderr and is silent unless the CLI
+// raised the level with -v or -vv.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum Level {
+    Off,
+    Debug,
+    Trace,
+}
+
+impl Level {
+    pub fn from_verbosity(verbosity: usize) -> Level {
+        match verbosity {
+            0 => Level::Off,
+            1 => Level::Debug,
+            _ => Level::Trace,
+        }
+    }
+}
+
+impl fmt::Display for Level {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Level::Off => write!(f, "off"),
+            Level::Debug => write!(f, "debug"),
+            Level::Trace => write!(f, "trace"),
+        }
+    }
+}
+
+static LEVEL: AtomicU8 = AtomicU8::new(Level::Off as u8);
+
+pub fn set_level(level: Level) {
+    LEVEL.store(level as u8, Ordering::Relaxed);
+}
+
+pub fn level() -> Level {
+    match LEVEL.load(Ordering::Relaxed) {
+        0 => Level::Off,
+        1 => Level::Debug,
+        _ => Level::Trace,
+    }
+}
+
+pub fn enabled(level: Level) -> bool {
+    level != Level::Off && level <= self::level()
+}
+
+pub fn write(level: Level, args: fmt::Arguments) {
+    eprintln!("[{level}] {args}");
+}
+
+// The arguments are only evaluated when the level is enabled, so grids can
+// be rendered inline without slowing down normal runs.
+#[macro_export]
+macro_rules! debug {
+    ($($arg:tt)*) => {
+        if $crate::log::enabled($crate::log::Level::Debug) {
+            $crate::log::write($crate::log::Level::Debug, format_args!($($arg)*));
+        }
+    };
+}
+
+#[macro_export]
+macro_rules! trace {
+    ($($arg:tt)*) => {
+        if $crate::log::enabled($crate::log::Level::Trace) {
+            $crate::log::write($crate::log::Level::Trace, format_args!($($arg)*));
+        }
+    };
+}
+
+pub fn render_grid(cols: i32, rows: i32, mut cell: impl FnMut(Pos) -> char) -> String {
+    let mut grid = String::with_capacity(((cols + 1) * rows).max(0) as usize);
+    for y in 0..rows {
+        for x in 0..cols {
+            grid.push(cell(Pos { x, y }));
+        }
+        grid.push('\n');
+    }
+    grid.pop();
+    grid
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_levels() {
+        assert_eq!(Level::from_verbosity(0), Level::Off);
+        assert_eq!(Level::from_verbosity(2), Level::Trace);
+        assert_eq!(Level::from_verbosity(5), Level::Trace);
+
+        assert_eq!(level(), Level::Off);
+        assert!(!enabled(Level::Debug));
+        assert!(!enabled(Level::Off));
+    }
+
+    #[test]
+    fn test_render_grid() {
+        let grid = render_grid(3, 2, |pos| if pos.x == pos.y { '#' } else { '.' });
+        assert_eq!(grid, "#..\n.#.");
+        assert_eq!(render_grid(0, 0, |_| '#'), "");
+    }
+}
@@ -1,8 +1,49 @@
-use crate::library::{parse_file, parse_lines};
+use crate::alloc::{self, AllocStats};
+use crate::error::{Error, Result};
+use crate::library::text::{InputHash, InputText};
+use crate::library::validate::{self, Check};
+use std::fmt;
+use std::ops::RangeInclusive;
+use std::str::FromStr;
+use std::sync::{Arc, OnceLock};
+use std::time::{Duration, Instant};
+
+#[cfg_attr(
+    not(any(feature = "year2023", feature = "year2024", feature = "year2025")),
+    allow(unused_macros)
+)]
+macro_rules! days {
+    ($($num:literal => $module:ident::$day:ident),* $(,)?) => {
+        $(mod $module;)*
+
+        const DAYS: &[RegisteredDay] = &[$((DayNum($num), &$module::$day {})),*];
+    };
+}
+
+#[cfg(feature = "year2023")]
 pub mod year_2023;
+#[cfg(feature = "year2024")]
 pub mod year_2024;
+#[cfg(feature = "year2025")]
 pub mod year_2025;
 
+// Every year with solutions in the source tree, including the ones whose
+// feature is disabled in this build.
+pub const SOURCE_YEARS: &[i32] = &[2023, 2024, 2025];
+
+pub const YEARS: &[&dyn Year] = &[
+    #[cfg(feature = "year2023")]
+    &year_2023::Year2023 {},
+    #[cfg(feature = "year2024")]
+    &year_2024::Year2024 {},
+    #[cfg(feature = "year2025")]
+    &year_2025::Year2025 {},
+];
+
+pub fn get_year(year: i32) -> Option<&'static dyn Year> {
+    YEARS.iter().copied().find(|y| y.year() == year)
+}
+
 #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
 pub struct DayNum(i32);
 impl DayNum {
@@ -13,26 +54,563 @@ impl DayNum {
             None
         }
     }
+
+    pub fn get(&self) -> i32 {
+        self.0
+    }
 }
 
-pub trait Year {
-    fn solve_day(&self, day: DayNum);
+impl fmt::Display for DayNum {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.0)
+    }
 }
 
-pub trait AdventDay {
-    fn solve(&self) {
-        panic!("Day not implemented yet!");
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Answer {
+    Number(i128),
+    Text(String),
+    Unsolved,
+}
+
+macro_rules! impl_answer_from_int {
+    ($($t:ty),*) => {
+        $(
+            impl From<$t> for Answer {
+                fn from(value: $t) -> Self {
+                    Answer::Number(value as i128)
+                }
+            }
+        )*
+    };
+}
+impl_answer_from_int!(i32, i64, u32, u64, usize);
+
+impl From<String> for Answer {
+    fn from(value: String) -> Self {
+        Answer::Text(value)
     }
+}
+
+impl From<&str> for Answer {
+    fn from(value: &str) -> Self {
+        Answer::Text(value.to_string())
+    }
+}
+
+impl fmt::Display for Answer {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Answer::Number(n) => write!(f, "{n}"),
+            Answer::Text(s) => write!(f, "{s}"),
+            Answer::Unsolved => write!(f, "-"),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub struct Timings {
+    pub parse: Duration,
+    pub part1: Duration,
+    pub part2: Duration,
+}
+
+impl Timings {
+    pub fn total(&self) -> Duration {
+        self.parse + self.part1 + self.part2
+    }
+}
+
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub struct Memory {
+    pub parse: AllocStats,
+    pub part1: AllocStats,
+    pub part2: AllocStats,
+}
+
+impl Memory {
+    pub fn peak(&self) -> u64 {
+        self.parse.peak.max(self.part1.peak).max(self.part2.peak)
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct Solution {
+    pub part1: Answer,
+    pub part2: Answer,
+    pub timings: Timings,
+    pub memory: Memory,
+    pub input_hash: Option<InputHash>,
+    pub cached: bool,
+}
 
-    fn get_input(&self) -> Vec<String> {
-        if let Ok(line_string) = parse_file(self.get_input_path()) {
-            parse_lines(&line_string)
+impl fmt::Display for Solution {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(f, "Part1 solution: {}", self.part1)?;
+        write!(f, "Part2 solution: {}", self.part2)
+    }
+}
+
+fn timed<R>(func: impl FnOnce() -> R) -> (R, Duration, AllocStats) {
+    let start = Instant::now();
+    let (result, stats) = alloc::measure(func);
+    (result, start.elapsed(), stats)
+}
+
+pub struct Param {
+    pub name: &'static str,
+    pub default: &'static str,
+}
+
+// Raised with the time limit when the caller stopped waiting for a day, so
+// long running loops can return early instead of burning CPU in the
+// background.
+#[derive(Debug, Clone, Default)]
+pub struct Cancel(Arc<OnceLock<Duration>>);
+
+impl Cancel {
+    pub fn cancel(&self, limit: Duration) {
+        let _ = self.0.set(limit);
+    }
+
+    pub fn is_cancelled(&self) -> bool {
+        self.0.get().is_some()
+    }
+
+    // Answers computed after a cancellation may be cut short, so they are
+    // turned into a timeout instead of being reported.
+    pub fn check(&self) -> Result<()> {
+        match self.0.get() {
+            Some(&limit) => Err(Error::Timeout(limit)),
+            None => Ok(()),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Default)]
+pub struct Context {
+    pub part: Option<usize>,
+    pub params: Vec<(String, String)>,
+    pub cancel: Cancel,
+}
+
+impl Context {
+    pub fn cancelled(&self) -> bool {
+        self.cancel.is_cancelled()
+    }
+
+    pub fn param<T: FromStr>(&self, name: &str) -> Result<T> {
+        let value = self
+            .params
+            .iter()
+            .find(|(key, _)| key == name)
+            .map(|(_, value)| value)
+            .ok_or_else(|| Error::usage(format!("parameter '{name}' is not declared")))?;
+        value
+            .parse()
+            .map_err(|_| Error::usage(format!("invalid value '{value}' for parameter '{name}'")))
+    }
+
+    pub fn resolve(
+        &self,
+        declared: &[Param],
+        overrides: &[(&'static str, &'static str)],
+    ) -> Result<Context> {
+        let mut params = declared
+            .iter()
+            .map(|param| (param.name.to_string(), param.default.to_string()))
+            .collect::<Vec<(String, String)>>();
+
+        let overrides = overrides
+            .iter()
+            .map(|(key, value)| (key.to_string(), value.to_string()));
+        for (key, value) in overrides.chain(self.params.iter().cloned()) {
+            match params.iter_mut().find(|(name, _)| *name == key) {
+                Some((_, current)) => *current = value,
+                None => {
+                    let available = declared
+                        .iter()
+                        .map(|param| format!("{} (default {})", param.name, param.default))
+                        .collect::<Vec<String>>();
+                    return Err(Error::usage(if available.is_empty() {
+                        format!("unknown parameter '{key}', this day takes no parameters")
+                    } else {
+                        format!(
+                            "unknown parameter '{key}', available: {}",
+                            available.join(", ")
+                        )
+                    }));
+                }
+            }
+        }
+
+        Ok(Context {
+            part: self.part,
+            params,
+            cancel: self.cancel.clone(),
+        })
+    }
+}
+
+pub struct Solver<T> {
+    parsed: T,
+    part: Option<usize>,
+    cancel: Cancel,
+    part1: Answer,
+    timings: Timings,
+    memory: Memory,
+}
+
+impl<T> Solver<T> {
+    pub fn new(ctx: &Context, parsed: T) -> Solver<T> {
+        Solver {
+            parsed,
+            part: ctx.part,
+            cancel: ctx.cancel.clone(),
+            part1: Answer::Unsolved,
+            timings: Timings::default(),
+            memory: Memory::default(),
+        }
+    }
+
+    pub fn parse(ctx: &Context, parse: impl FnOnce() -> T) -> Solver<T> {
+        let (parsed, duration, stats) = timed(parse);
+        let mut solver = Solver::new(ctx, parsed);
+        solver.timings.parse = duration;
+        solver.memory.parse = stats;
+        solver
+    }
+
+    pub fn try_parse(ctx: &Context, parse: impl FnOnce() -> Result<T>) -> Result<Solver<T>> {
+        let (parsed, duration, stats) = timed(parse);
+        let mut solver = Solver::new(ctx, parsed?);
+        solver.timings.parse = duration;
+        solver.memory.parse = stats;
+        Ok(solver)
+    }
+
+    fn runs_part(&self, part: usize) -> bool {
+        self.part.is_none_or(|p| p == part)
+    }
+
+    pub fn part1<A: Into<Answer>>(mut self, solve: impl FnOnce(&T) -> A) -> Solver<T> {
+        if self.runs_part(1) {
+            let (answer, duration, stats) = timed(|| solve(&self.parsed).into());
+            self.part1 = answer;
+            self.timings.part1 = duration;
+            self.memory.part1 = stats;
+        }
+        self
+    }
+
+    pub fn part2<A: Into<Answer>>(mut self, solve: impl FnOnce(&T) -> A) -> Result<Solution> {
+        if !self.runs_part(2) {
+            return self.finish();
+        }
+
+        let (answer, duration, stats) = timed(|| solve(&self.parsed).into());
+        self.cancel.check()?;
+        self.timings.part2 = duration;
+        self.memory.part2 = stats;
+        Ok(Solution {
+            part1: self.part1,
+            part2: answer,
+            timings: self.timings,
+            memory: self.memory,
+            input_hash: None,
+            cached: false,
+        })
+    }
+
+    pub fn finish(self) -> Result<Solution> {
+        self.cancel.check()?;
+        Ok(Solution {
+            part1: self.part1,
+            part2: Answer::Unsolved,
+            timings: self.timings,
+            memory: self.memory,
+            input_hash: None,
+            cached: false,
+        })
+    }
+}
+
+pub type RegisteredDay = (DayNum, &'static dyn AdventDay);
+
+pub struct Example {
+    pub name: &'static str,
+    pub input: &'static str,
+    pub part1: Option<&'static str>,
+    pub part2: Option<&'static str>,
+    pub params: &'static [(&'static str, &'static str)],
+}
+
+impl Example {
+    pub fn expected(&self, part: usize) -> Option<&'static str> {
+        match part {
+            1 => self.part1,
+            2 => self.part2,
+            _ => None,
+        }
+    }
+}
+
+pub trait Year {
+    fn year(&self) -> i32;
+
+    fn day_range(&self) -> RangeInclusive<i32>;
+
+    fn days(&self) -> &'static [RegisteredDay];
+
+    fn day_num(&self, day: i32) -> Result<DayNum> {
+        let range = self.day_range();
+        if range.contains(&day) {
+            Ok(DayNum(day))
         } else {
-            panic!("Could not get/parse input");
+            Err(Error::usage(format!(
+                "day {day} does not exist in the {} event, which has days {} to {}",
+                self.year(),
+                range.start(),
+                range.end()
+            )))
         }
     }
 
+    fn find_day(&self, day: DayNum) -> Result<&'static dyn AdventDay> {
+        self.get_day(day).ok_or_else(|| {
+            Error::usage(format!("{} day {day} is not implemented yet", self.year()))
+        })
+    }
+
+    fn get_day(&self, day: DayNum) -> Option<&'static dyn AdventDay> {
+        self.days()
+            .iter()
+            .find(|(day_num, _)| *day_num == day)
+            .map(|(_, advent_day)| *advent_day)
+    }
+}
+
+pub trait AdventDay: Send + Sync {
+    fn solve(&self, _lines: &[String], _ctx: &Context) -> Result<Solution> {
+        panic!("Day not implemented yet!");
+    }
+
+    fn solve_input(&self, input: &InputText, ctx: &Context) -> Result<Solution> {
+        self.solve(&input.to_lines(), ctx)
+    }
+
     fn get_input_path(&self) -> &str {
         panic!("get_input_path not implemented for this day!");
     }
+
+    fn examples(&self) -> &'static [Example] {
+        &[]
+    }
+
+    fn params(&self) -> &'static [Param] {
+        &[]
+    }
+
+    fn input_checks(&self) -> &'static [Check] {
+        &[]
+    }
+
+    fn get_example(&self, name: &str) -> Option<&'static Example> {
+        self.examples().iter().find(|example| example.name == name)
+    }
+}
+
+pub fn validate_input(
+    year: i32,
+    day: DayNum,
+    advent_day: &dyn AdventDay,
+    input: &InputText,
+) -> Result<()> {
+    validate::validate(input, advent_day.input_checks())
+        .map_err(|reason| Error::mismatch(format!("{year} day {day}"), reason))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_answer_from() {
+        assert_eq!(Answer::from(42_usize), Answer::Number(42));
+        assert_eq!(Answer::from(-7_i32), Answer::Number(-7));
+        assert_eq!(Answer::from(u64::MAX), Answer::Number(u64::MAX as i128));
+        assert_eq!(Answer::from("4,6,3"), Answer::Text("4,6,3".to_string()));
+    }
+
+    #[test]
+    fn test_solver() {
+        let ctx = Context::default();
+        let solution = Solver::parse(&ctx, || vec![1, 2, 3])
+            .part1(|nums| nums.iter().sum::<i32>())
+            .part2(|nums| format!("{nums:?}"))
+            .unwrap();
+        assert_eq!(solution.part1, Answer::Number(6));
+        assert_eq!(solution.part2, Answer::Text("[1, 2, 3]".to_string()));
+        assert_eq!(
+            solution.to_string(),
+            "Part1 solution: 6\nPart2 solution: [1, 2, 3]"
+        );
+
+        let solution = Solver::new(&ctx, 3_usize)
+            .part1(|n| n * 2)
+            .finish()
+            .unwrap();
+        assert_eq!(solution.part1, Answer::Number(6));
+        assert_eq!(solution.part2, Answer::Unsolved);
+        assert_eq!(solution.timings.parse, Duration::ZERO);
+
+        let ctx = Context {
+            part: Some(2),
+            ..Context::default()
+        };
+        let solution = Solver::new(&ctx, 3_usize)
+            .part1(|_| -> usize { panic!("part1 should not run") })
+            .part2(|n| n * 3)
+            .unwrap();
+        assert_eq!(solution.part1, Answer::Unsolved);
+        assert_eq!(solution.part2, Answer::Number(9));
+
+        let limit = Duration::from_millis(500);
+        let result = Solver::new(&ctx, 3_usize)
+            .part2(|n| {
+                ctx.cancel.cancel(limit);
+                n * 3
+            })
+            .unwrap_err();
+        assert!(matches!(result, Error::Timeout(l) if l == limit));
+        assert!(Solver::new(&ctx, 3_usize).finish().is_err());
+    }
+
+    #[test]
+    fn test_context_params() {
+        let declared = [
+            Param {
+                name: "width",
+                default: "101",
+            },
+            Param {
+                name: "height",
+                default: "103",
+            },
+        ];
+        let ctx = Context {
+            part: None,
+            params: vec![("height".to_string(), "7".to_string())],
+            ..Context::default()
+        };
+
+        let resolved = ctx
+            .resolve(&declared, &[("width", "11"), ("height", "5")])
+            .unwrap();
+        assert_eq!(resolved.param::<i32>("width").unwrap(), 11);
+        assert_eq!(resolved.param::<i32>("height").unwrap(), 7);
+        assert!(resolved.param::<i32>("depth").is_err());
+
+        ctx.cancel.cancel(Duration::from_secs(1));
+        assert!(resolved.cancelled());
+
+        let ctx = Context {
+            part: None,
+            params: vec![("depth".to_string(), "3".to_string())],
+            ..Context::default()
+        };
+        assert!(!ctx.cancelled());
+        assert_eq!(
+            ctx.resolve(&declared, &[]).unwrap_err().to_string(),
+            "unknown parameter 'depth', available: width (default 101), height (default 103)"
+        );
+    }
+
+    #[test]
+    fn test_registry() {
+        for year in YEARS {
+            let days = year.days();
+            assert!(!days.is_empty());
+            assert!(days.windows(2).all(|w| w[0].0 < w[1].0));
+            assert!(
+                days.iter()
+                    .all(|(day, _)| year.day_range().contains(&day.0))
+            );
+            assert!(get_year(year.year()).is_some());
+            assert!(SOURCE_YEARS.contains(&year.year()));
+        }
+        assert!(get_year(2015).is_none());
+    }
+
+    #[cfg(feature = "year2024")]
+    #[test]
+    fn test_registry_2024() {
+        let year = get_year(2024).unwrap();
+        assert_eq!(year.days().len(), 25);
+        assert!(year.get_day(DayNum(25)).is_some());
+    }
+
+    #[cfg(feature = "year2024")]
+    #[test]
+    fn test_validate_input_2024() {
+        let day = DayNum(16);
+        let advent_day = get_year(2024).unwrap().find_day(day).unwrap();
+        let input = InputText::from("O....#....\nO.OO#....#");
+        assert_eq!(
+            validate_input(2024, day, advent_day, &input)
+                .unwrap_err()
+                .to_string(),
+            "this doesn't look like a 2024 day 16 input (line 1, column 1: unexpected character 'O')"
+        );
+    }
+
+    #[cfg(feature = "year2025")]
+    #[test]
+    fn test_day_range_2025() {
+        let year = get_year(2025).unwrap();
+        assert!(year.get_day(DayNum(25)).is_none());
+        assert_eq!(year.day_num(12).unwrap(), DayNum(12));
+        assert_eq!(
+            year.day_num(13).unwrap_err().to_string(),
+            "day 13 does not exist in the 2025 event, which has days 1 to 12"
+        );
+        assert_eq!(
+            year.find_day(DayNum(12)).err().unwrap().to_string(),
+            "2025 day 12 is not implemented yet"
+        );
+    }
+
+    #[test]
+    fn test_examples() {
+        let mut failures = Vec::new();
+        for year in YEARS {
+            for (day, advent_day) in year.days() {
+                for example in advent_day.examples() {
+                    let input = InputText::from(example.input);
+                    if let Err(e) = validate_input(year.year(), *day, *advent_day, &input) {
+                        failures.push(format!("example '{}': {e}", example.name));
+                        continue;
+                    }
+
+                    let ctx = Context::default()
+                        .resolve(advent_day.params(), example.params)
+                        .unwrap();
+                    let solution = advent_day.solve_input(&input, &ctx).unwrap();
+                    for (part, answer) in [(1, &solution.part1), (2, &solution.part2)] {
+                        if let Some(expected) = example.expected(part)
+                            && expected != answer.to_string()
+                        {
+                            failures.push(format!(
+                                "{} day {day} example '{}' part{part}: expected {expected}, got {answer}",
+                                year.year(),
+                                example.name
+                            ));
+                        }
+                    }
+                }
+            }
+        }
+
+        assert!(failures.is_empty(), "{}", failures.join("\n"));
+    }
 }
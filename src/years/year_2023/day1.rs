@@ -1,17 +1,27 @@
-use crate::years::AdventDay;
+use crate::error::Result;
+use crate::library::validate::Check;
+use crate::years::{AdventDay, Context, Example, Solution, Solver};
 
 pub struct Day1 {}
 
 impl AdventDay for Day1 {
-    fn solve(&self) {
-        let lines = self.get_input();
-        println!("Part1 solution: {}", part1(&lines));
-        println!("Part2 solution: {}", part2(&lines));
+    fn solve(&self, lines: &[String], ctx: &Context) -> Result<Solution> {
+        Solver::new(ctx, lines)
+            .part1(|lines| part1(lines))
+            .part2(|lines| part2(lines))
     }
 
     fn get_input_path(&self) -> &str {
         "Inputs/2023/day1.txt"
     }
+
+    fn examples(&self) -> &'static [Example] {
+        EXAMPLES
+    }
+
+    fn input_checks(&self) -> &'static [Check] {
+        &[Check::Lines(r"[a-z0-9]+")]
+    }
 }
 
 fn get_string_num_matches(line: &str, pattern: &str, char: char) -> Vec<(usize, char)> {
@@ -47,9 +57,13 @@ fn get_number_chars(line: &str, allow_number_names: bool) -> Vec<char> {
 }
 
 fn create_calibration_num(number_chars: &[char]) -> u32 {
+    let (Some(first), Some(last)) = (number_chars.first(), number_chars.last()) else {
+        return 0;
+    };
+
     let mut new_num = String::new();
-    new_num.push(*number_chars.first().unwrap());
-    new_num.push(*number_chars.last().unwrap());
+    new_num.push(*first);
+    new_num.push(*last);
 
     new_num.parse::<u32>().unwrap_or(0)
 }
@@ -74,22 +88,12 @@ fn part2(lines: &[String]) -> u32 {
         .sum()
 }
 
-#[cfg(test)]
-mod tests {
-    use super::*;
-    use crate::library::*;
-
-    fn get_lines_part_1() -> Vec<String> {
-        let input = r#"1abc2
+const EXAMPLE_PART1: &str = r#"1abc2
 pqr3stu8vwx
 a1b2c3d4e5f
 treb7uchet"#;
 
-        parse_lines(&input)
-    }
-
-    fn get_lines_part_2() -> Vec<String> {
-        let input = r#"two1nine
+const EXAMPLE_PART2: &str = r#"two1nine
 eightwothree
 abcone2threexyz
 xtwone3four
@@ -97,7 +101,34 @@ xtwone3four
 zoneight234
 7pqrstsixteen"#;
 
-        parse_lines(&input)
+const EXAMPLES: &[Example] = &[
+    Example {
+        name: "part1",
+        input: EXAMPLE_PART1,
+        part1: Some("142"),
+        part2: None,
+        params: &[],
+    },
+    Example {
+        name: "part2",
+        input: EXAMPLE_PART2,
+        part1: None,
+        part2: Some("281"),
+        params: &[],
+    },
+];
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::library::*;
+
+    fn get_lines_part_1() -> Vec<String> {
+        parse_lines(EXAMPLE_PART1)
+    }
+
+    fn get_lines_part_2() -> Vec<String> {
+        parse_lines(EXAMPLE_PART2)
     }
 
     #[test]
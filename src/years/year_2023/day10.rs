@@ -1,21 +1,30 @@
 use std::collections::VecDeque;
 
 use super::{DIRECTIONS, DOWN, Dir, LEFT, Map, Pos, RIGHT, UP};
-use crate::years::AdventDay;
+use crate::error::{Error, Result};
+use crate::library::validate::Check;
+use crate::years::{AdventDay, Context, Example, Solution, Solver};
 
 pub struct Day10 {}
 
 impl AdventDay for Day10 {
-    fn solve(&self) {
-        let lines = self.get_input();
-        let (start_pos, map) = parse_map(&lines);
-        println!("Part1 solution: {}", part1(&start_pos, &map));
-        println!("Part2 solution: {}", part2(&start_pos, &map));
+    fn solve(&self, lines: &[String], ctx: &Context) -> Result<Solution> {
+        Solver::try_parse(ctx, || parse_map(lines))?
+            .part1(|(start_pos, map)| part1(start_pos, map))
+            .part2(|(start_pos, map)| part2(start_pos, map))
     }
 
     fn get_input_path(&self) -> &str {
         "Inputs/2023/day10.txt"
     }
+
+    fn examples(&self) -> &'static [Example] {
+        EXAMPLES
+    }
+
+    fn input_checks(&self) -> &'static [Check] {
+        &[Check::Chars("|-LJ7F.S"), Check::Grid]
+    }
 }
 
 #[derive(Debug, Clone)]
@@ -98,7 +107,7 @@ fn shoelace_formula(pipe_loop: &Vec<&Pos>) -> usize {
     ((area.abs() + perimeter) / 2 + 1) as usize
 }
 
-fn parse_start_pos_type(start_pos: &Pos, map: &mut Map<Pipe>) {
+fn parse_start_pos_type(start_pos: &Pos, map: &mut Map<Pipe>) -> Result<()> {
     let mut pipes_connected_to_start = DIRECTIONS
         .iter()
         .filter(|dir| {
@@ -109,10 +118,16 @@ fn parse_start_pos_type(start_pos: &Pos, map: &mut Map<Pipe>) {
         })
         .collect::<Vec<&&Dir>>();
 
-    assert!(
-        pipes_connected_to_start.len() == 2,
-        "Expected start pos to only be connected to two pipes!"
-    );
+    if pipes_connected_to_start.len() != 2 {
+        return Err(Error::parse_at(
+            start_pos.y as usize,
+            start_pos.x as usize,
+            format!(
+                "expected the start to connect to two pipes, found {}",
+                pipes_connected_to_start.len()
+            ),
+        ));
+    }
 
     pipes_connected_to_start.sort();
 
@@ -122,56 +137,150 @@ fn parse_start_pos_type(start_pos: &Pos, map: &mut Map<Pipe>) {
             directions: Some((pipes_connected_to_start[0], pipes_connected_to_start[1])),
             pos: start_pos.clone(),
         },
-    )
+    );
+    Ok(())
 }
 
-fn parse_map<'a>(lines: &[String]) -> (Pos, Map<Pipe<'a>>) {
-    let mut start_pos = Pos { x: 0, y: 0 };
+fn parse_map<'a>(lines: &[String]) -> Result<(Pos, Map<Pipe<'a>>)> {
+    let mut start_pos = None;
 
-    let mut map = Map::new(lines, |char, pos| match char {
-        '|' => Pipe {
-            directions: Some((UP, DOWN)),
-            pos: pos.clone(),
-        },
-        '-' => Pipe {
-            directions: Some((LEFT, RIGHT)),
-            pos: pos.clone(),
-        },
-        'L' => Pipe {
-            directions: Some((UP, RIGHT)),
-            pos: pos.clone(),
-        },
-        'J' => Pipe {
-            directions: Some((UP, LEFT)),
-            pos: pos.clone(),
-        },
-        '7' => Pipe {
-            directions: Some((DOWN, LEFT)),
-            pos: pos.clone(),
-        },
-        'F' => Pipe {
-            directions: Some((DOWN, RIGHT)),
-            pos: pos.clone(),
-        },
-        '.' => Pipe {
-            directions: None,
-            pos: pos.clone(),
-        },
-        'S' => {
-            start_pos = pos.clone();
-            Pipe {
+    let mut map = Map::try_new(lines, |char, pos| {
+        Ok(match char {
+            '|' => Pipe {
+                directions: Some((UP, DOWN)),
+                pos: pos.clone(),
+            },
+            '-' => Pipe {
+                directions: Some((LEFT, RIGHT)),
+                pos: pos.clone(),
+            },
+            'L' => Pipe {
+                directions: Some((UP, RIGHT)),
+                pos: pos.clone(),
+            },
+            'J' => Pipe {
+                directions: Some((UP, LEFT)),
+                pos: pos.clone(),
+            },
+            '7' => Pipe {
+                directions: Some((DOWN, LEFT)),
+                pos: pos.clone(),
+            },
+            'F' => Pipe {
+                directions: Some((DOWN, RIGHT)),
+                pos: pos.clone(),
+            },
+            '.' => Pipe {
                 directions: None,
                 pos: pos.clone(),
+            },
+            'S' => {
+                start_pos = Some(pos.clone());
+                Pipe {
+                    directions: None,
+                    pos: pos.clone(),
+                }
             }
-        }
-        _ => panic!("Got unexpected char '{char}' when trying to parse map!"),
-    });
+            _ => {
+                return Err(Error::parse_at(
+                    pos.y as usize,
+                    pos.x as usize,
+                    format!("expected a pipe, '.' or 'S', got '{char}'"),
+                ));
+            }
+        })
+    })?;
 
-    parse_start_pos_type(&start_pos, &mut map);
+    let Some(start_pos) = start_pos else {
+        return Err(Error::parse(0, "expected a start position 'S'"));
+    };
+    parse_start_pos_type(&start_pos, &mut map)?;
 
-    (start_pos, map)
+    Ok((start_pos, map))
 }
 
+const EXAMPLE_SMALL_LOOP: &str = r#"-L|F7
+7S-7|
+L|7||
+-L-J|
+L|-JF"#;
+
+const EXAMPLE_SMALL_COMPLICATED: &str = r#"..F7.
+.FJ|.
+SJ.L7
+|F--J
+LJ..."#;
+
+const EXAMPLE_LARGE_LOOP: &str = r#"...........
+.S-------7.
+.|F-----7|.
+.||.....||.
+.||.....||.
+.|L-7.F-J|.
+.|..|.|..|.
+.L--J.L--J.
+..........."#;
+
+const EXAMPLE_LARGE_COMPLICATED: &str = r#".F----7F7F7F7F-7....
+.|F--7||||||||FJ....
+.||.FJ||||||||L7....
+FJL7L7LJLJ||LJ.L-7..
+L--J.L7...LJS7F-7L7.
+....F-J..F7FJ|L7L7L7
+....L7.F7||L7|.L7L7|
+.....|FJLJ|FJ|F7|.LJ
+....FJL-7.||.||||...
+....L---J.LJ.LJLJ..."#;
+
+const EXAMPLE_LARGE_COMPLICATED_2: &str = r#"FF7FSF7F7F7F7F7F---7
+L|LJ||||||||||||F--J
+FL-7LJLJ||||||LJL-77
+F--JF--7||LJLJ7F7FJ-
+L---JF-JLJ.||-FJLJJ7
+|F|F-JF---7F7-L7L|7|
+|FFJF7L7F-JF7|JL---7
+7-L-JL7||F7|L7F-7F7|
+L.L7LFJ|||||FJL7||LJ
+L7JLJL-JLJLJL--JLJ.L"#;
+
+const EXAMPLES: &[Example] = &[
+    Example {
+        name: "small-loop",
+        input: EXAMPLE_SMALL_LOOP,
+        part1: Some("4"),
+        part2: None,
+        params: &[],
+    },
+    Example {
+        name: "small-complicated",
+        input: EXAMPLE_SMALL_COMPLICATED,
+        part1: Some("8"),
+        part2: None,
+        params: &[],
+    },
+    Example {
+        name: "large-loop",
+        input: EXAMPLE_LARGE_LOOP,
+        part1: None,
+        part2: Some("4"),
+        params: &[],
+    },
+    Example {
+        name: "large-complicated",
+        input: EXAMPLE_LARGE_COMPLICATED,
+        part1: None,
+        part2: Some("8"),
+        params: &[],
+    },
+    Example {
+        name: "large-complicated-2",
+        input: EXAMPLE_LARGE_COMPLICATED_2,
+        part1: None,
+        part2: Some("10"),
+        params: &[],
+    },
+];
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -218,66 +327,45 @@ mod tests {
     }
 
     fn get_small_loop_map<'a>() -> (Pos, Map<Pipe<'a>>) {
-        let input = r#"-L|F7
-7S-7|
-L|7||
--L-J|
-L|-JF"#;
-
-        parse_map(&parse_lines(&input))
+        parse_map(&parse_lines(EXAMPLE_SMALL_LOOP)).unwrap()
     }
 
     fn get_small_complicated_map<'a>() -> (Pos, Map<Pipe<'a>>) {
-        let input = r#"..F7.
-.FJ|.
-SJ.L7
-|F--J
-LJ..."#;
-
-        parse_map(&parse_lines(&input))
+        parse_map(&parse_lines(EXAMPLE_SMALL_COMPLICATED)).unwrap()
     }
 
     fn get_large_loop_map<'a>() -> (Pos, Map<Pipe<'a>>) {
-        let input = r#"...........
-.S-------7.
-.|F-----7|.
-.||.....||.
-.||.....||.
-.|L-7.F-J|.
-.|..|.|..|.
-.L--J.L--J.
-..........."#;
-
-        parse_map(&parse_lines(&input))
+        parse_map(&parse_lines(EXAMPLE_LARGE_LOOP)).unwrap()
     }
 
     fn get_large_complicated_map<'a>() -> (Pos, Map<Pipe<'a>>) {
-        let input = r#".F----7F7F7F7F-7....
-.|F--7||||||||FJ....
-.||.FJ||||||||L7....
-FJL7L7LJLJ||LJ.L-7..
-L--J.L7...LJS7F-7L7.
-....F-J..F7FJ|L7L7L7
-....L7.F7||L7|.L7L7|
-.....|FJLJ|FJ|F7|.LJ
-....FJL-7.||.||||...
-....L---J.LJ.LJLJ..."#;
-
-        parse_map(&parse_lines(&input))
+        parse_map(&parse_lines(EXAMPLE_LARGE_COMPLICATED)).unwrap()
     }
 
     fn get_large_complicated_map_2<'a>() -> (Pos, Map<Pipe<'a>>) {
-        let input = r#"FF7FSF7F7F7F7F7F---7
-L|LJ||||||||||||F--J
-FL-7LJLJ||||||LJL-77
-F--JF--7||LJLJ7F7FJ-
-L---JF-JLJ.||-FJLJJ7
-|F|F-JF---7F7-L7L|7|
-|FFJF7L7F-JF7|JL---7
-7-L-JL7||F7|L7F-7F7|
-L.L7LFJ|||||FJL7||LJ
-L7JLJL-JLJLJL--JLJ.L"#;
+        parse_map(&parse_lines(EXAMPLE_LARGE_COMPLICATED_2)).unwrap()
+    }
 
-        parse_map(&parse_lines(&input))
+    #[test]
+    fn test_parse_error() {
+        let error = parse_map(&parse_lines(".S-7\n.|x|\n.L-J")).err().unwrap();
+        assert!(matches!(
+            error,
+            Error::Parse {
+                line: 2,
+                column: Some(3),
+                ..
+            }
+        ));
+
+        let error = parse_map(&parse_lines(".S-7\n...|\n.L-J")).err().unwrap();
+        assert!(matches!(
+            error,
+            Error::Parse {
+                line: 1,
+                column: Some(2),
+                ..
+            }
+        ));
     }
 }
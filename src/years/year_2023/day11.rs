@@ -1,19 +1,36 @@
 use super::Pos;
-use crate::years::AdventDay;
+use crate::error::{Error, Result};
+use crate::library::validate::Check;
+use crate::years::{AdventDay, Context, Example, Param, Solution, Solver};
 
 pub struct Day11 {}
 
 impl AdventDay for Day11 {
-    fn solve(&self) {
-        let lines = self.get_input();
-        let (galaxies, empty_space) = parse_map(&lines);
-        println!("Part1 solution: {}", part1(&galaxies, &empty_space));
-        println!("Part2 solution: {}", part2(&galaxies, &empty_space));
+    fn solve(&self, lines: &[String], ctx: &Context) -> Result<Solution> {
+        let expansion = ctx.param("expansion")?;
+        Solver::try_parse(ctx, || parse_map(lines))?
+            .part1(|(galaxies, empty_space)| part1(galaxies, empty_space))
+            .part2(|(galaxies, empty_space)| part2(galaxies, empty_space, expansion))
     }
 
     fn get_input_path(&self) -> &str {
         "Inputs/2023/day11.txt"
     }
+
+    fn examples(&self) -> &'static [Example] {
+        EXAMPLES
+    }
+
+    fn input_checks(&self) -> &'static [Check] {
+        &[Check::Chars(".#"), Check::SquareGrid]
+    }
+
+    fn params(&self) -> &'static [Param] {
+        &[Param {
+            name: "expansion",
+            default: "1000000",
+        }]
+    }
 }
 
 #[derive(Debug)]
@@ -62,27 +79,36 @@ fn part1(galaxies: &[Galaxy], empty_space: &EmptySpace) -> usize {
     calculate_expanded_galaxy_distances(galaxies, empty_space, 2)
 }
 
-fn part2(galaxies: &[Galaxy], empty_space: &EmptySpace) -> usize {
-    calculate_expanded_galaxy_distances(galaxies, empty_space, 1000000)
+fn part2(galaxies: &[Galaxy], empty_space: &EmptySpace, expansion: usize) -> usize {
+    calculate_expanded_galaxy_distances(galaxies, empty_space, expansion)
 }
 
-fn parse_map(lines: &[String]) -> (Vec<Galaxy>, EmptySpace) {
+fn parse_map(lines: &[String]) -> Result<(Vec<Galaxy>, EmptySpace)> {
     let mut galaxies = Vec::new();
 
     for (row, line) in lines.iter().enumerate() {
-        for (col, c) in line.chars().enumerate() {
+        for (col, c) in line.char_indices() {
             let pos = Pos {
                 x: col as i32,
                 y: row as i32,
             };
-            if c == '#' {
-                galaxies.push(pos);
+            match c {
+                '#' => galaxies.push(pos),
+                '.' => {}
+                c => {
+                    return Err(Error::parse_at(
+                        row,
+                        col,
+                        format!("expected '.' or '#', got '{c}'"),
+                    ));
+                }
             }
         }
     }
 
-    let rows = galaxies.iter().map(|galaxy| galaxy.y).max().unwrap();
-    let cols = galaxies.iter().map(|galaxy| galaxy.x).max().unwrap();
+    // Without any galaxies there is no space to expand
+    let rows = galaxies.iter().map(|galaxy| galaxy.y).max().unwrap_or(-1);
+    let cols = galaxies.iter().map(|galaxy| galaxy.x).max().unwrap_or(-1);
 
     let rows = (0..=rows)
         .filter(|row| !galaxies.iter().any(|galaxy| galaxy.y == *row))
@@ -91,9 +117,28 @@ fn parse_map(lines: &[String]) -> (Vec<Galaxy>, EmptySpace) {
         .filter(|col| !galaxies.iter().any(|galaxy| galaxy.x == *col))
         .collect::<Vec<i32>>();
 
-    (galaxies, EmptySpace { rows, cols })
+    Ok((galaxies, EmptySpace { rows, cols }))
 }
 
+const EXAMPLE: &str = r#"...#......
+.......#..
+#.........
+..........
+......#...
+.#........
+.........#
+..........
+.......#..
+#...#....."#;
+
+const EXAMPLES: &[Example] = &[Example {
+    name: "example",
+    input: EXAMPLE,
+    part1: Some("374"),
+    part2: Some("8410"),
+    params: &[("expansion", "100")],
+}];
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -129,18 +174,20 @@ mod tests {
         Ok(())
     }
 
-    fn get_input<'a>() -> (Vec<Galaxy>, EmptySpace) {
-        let input = r#"...#......
-.......#..
-#.........
-..........
-......#...
-.#........
-.........#
-..........
-.......#..
-#...#....."#;
+    fn get_input() -> (Vec<Galaxy>, EmptySpace) {
+        parse_map(&parse_lines(EXAMPLE)).unwrap()
+    }
 
-        parse_map(&parse_lines(&input))
+    #[test]
+    fn test_parse_error() {
+        let error = parse_map(&parse_lines("...#\n.#?.")).unwrap_err();
+        assert!(matches!(
+            error,
+            Error::Parse {
+                line: 2,
+                column: Some(3),
+                ..
+            }
+        ));
     }
 }
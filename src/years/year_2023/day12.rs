@@ -1,20 +1,30 @@
 use std::collections::HashMap;
 
-use crate::years::AdventDay;
+use crate::error::{Error, Result};
+use crate::library::parse::parse_number;
+use crate::library::validate::Check;
+use crate::years::{AdventDay, Context, Example, Solution, Solver};
 
 pub struct Day12 {}
 
 impl AdventDay for Day12 {
-    fn solve(&self) {
-        let lines = self.get_input();
-        let spring_statuses = parse_records(&lines);
-        println!("Part1 solution: {}", part1(&spring_statuses));
-        println!("Part2 solution: {}", part2(&spring_statuses));
+    fn solve(&self, lines: &[String], ctx: &Context) -> Result<Solution> {
+        Solver::try_parse(ctx, || parse_records(lines))?
+            .part1(|spring_statuses| part1(spring_statuses))
+            .part2(|spring_statuses| part2(spring_statuses))
     }
 
     fn get_input_path(&self) -> &str {
         "Inputs/2023/day12.txt"
     }
+
+    fn examples(&self) -> &'static [Example] {
+        EXAMPLES
+    }
+
+    fn input_checks(&self) -> &'static [Check] {
+        &[Check::Lines(r"[.#?]+ \d+(,\d+)*")]
+    }
 }
 
 #[derive(Debug, Clone, Copy, PartialEq)]
@@ -144,41 +154,63 @@ fn part2(spring_statuses: &[SpringStatus]) -> usize {
     part1(&unfolded_spring_statuses)
 }
 
-fn parse_string_status(line: &str) -> Vec<Status> {
-    line.chars()
-        .map(|c| match c {
-            '.' => Status::Operational,
-            '#' => Status::Damage,
-            '?' => Status::Unknown,
-            _ => panic!("Got unexpected char '{c}' when trying to parse string status"),
+fn parse_string_status(line: &str, line_idx: usize) -> Result<Vec<Status>> {
+    line.char_indices()
+        .map(|(col_idx, c)| match c {
+            '.' => Ok(Status::Operational),
+            '#' => Ok(Status::Damage),
+            '?' => Ok(Status::Unknown),
+            _ => Err(Error::parse_at(
+                line_idx,
+                col_idx,
+                format!("expected '.', '#' or '?', got '{c}'"),
+            )),
         })
         .collect()
 }
 
-fn parse_integer_status(line: &str) -> Vec<usize> {
-    line.split(',').filter_map(|i| i.parse().ok()).collect()
+fn parse_integer_status(line: &str, int_part: &str, line_idx: usize) -> Result<Vec<usize>> {
+    int_part
+        .split(',')
+        .map(|i| parse_number(line, i, line_idx))
+        .collect()
 }
 
-fn parse_records(lines: &[String]) -> Vec<SpringStatus> {
+fn parse_records(lines: &[String]) -> Result<Vec<SpringStatus>> {
     lines
         .iter()
-        .map(|line| {
-            let mut parts = line.split_ascii_whitespace();
-            let str_part = parts
-                .next()
-                .expect("Status as a line consisting of '.', '#' and '?'");
-            let int_part = parts
-                .next()
-                .expect("Status on the form of comma separated integers");
-
-            (
-                parse_string_status(str_part),
-                parse_integer_status(int_part),
-            )
+        .enumerate()
+        .map(|(line_idx, line)| {
+            let Some((str_part, int_part)) = line.split_once(' ') else {
+                return Err(Error::parse(
+                    line_idx,
+                    "expected '<statuses> <comma separated group sizes>'",
+                ));
+            };
+
+            Ok((
+                parse_string_status(str_part, line_idx)?,
+                parse_integer_status(line, int_part, line_idx)?,
+            ))
         })
         .collect()
 }
 
+const EXAMPLE: &str = r#"???.### 1,1,3
+.??..??...?##. 1,1,3
+?#?#?#?#?#?#?#? 1,3,1,6
+????.#...#... 4,1,1
+????.######..#####. 1,6,5
+?###???????? 3,2,1"#;
+
+const EXAMPLES: &[Example] = &[Example {
+    name: "example",
+    input: EXAMPLE,
+    part1: Some("21"),
+    part2: Some("525152"),
+    params: &[],
+}];
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -200,14 +232,30 @@ mod tests {
         Ok(())
     }
 
-    fn get_input<'a>() -> Vec<SpringStatus> {
-        let input = r#"???.### 1,1,3
-.??..??...?##. 1,1,3
-?#?#?#?#?#?#?#? 1,3,1,6
-????.#...#... 4,1,1
-????.######..#####. 1,6,5
-?###???????? 3,2,1"#;
+    fn get_input() -> Vec<SpringStatus> {
+        parse_records(&parse_lines(EXAMPLE)).unwrap()
+    }
+
+    #[test]
+    fn test_parse_error() {
+        let error = parse_records(&parse_lines("???.### 1,1,3\n.??x 1")).unwrap_err();
+        assert!(matches!(
+            error,
+            Error::Parse {
+                line: 2,
+                column: Some(4),
+                ..
+            }
+        ));
 
-        parse_records(&parse_lines(&input))
+        let error = parse_records(&parse_lines("???.### 1,,3")).unwrap_err();
+        assert!(matches!(
+            error,
+            Error::Parse {
+                line: 1,
+                column: Some(11),
+                ..
+            }
+        ));
     }
 }
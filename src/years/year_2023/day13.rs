@@ -1,19 +1,29 @@
 use super::{Map, Pos};
-use crate::years::AdventDay;
+use crate::error::{Error, Result};
+use crate::library::text::InputText;
+use crate::library::validate::Check;
+use crate::years::{AdventDay, Context, Example, Solution, Solver};
 
 pub struct Day13 {}
 
 impl AdventDay for Day13 {
-    fn solve(&self) {
-        let lines = self.get_input();
-        let maps = parse_maps(&lines);
-        println!("Part1 solution: {}", part1(&maps));
-        println!("Part2 solution: {}", part2(&maps));
+    fn solve_input(&self, input: &InputText, ctx: &Context) -> Result<Solution> {
+        Solver::try_parse(ctx, || parse_maps(input))?
+            .part1(|maps| part1(maps))
+            .part2(|maps| part2(maps))
     }
 
     fn get_input_path(&self) -> &str {
         "Inputs/2023/day13.txt"
     }
+
+    fn examples(&self) -> &'static [Example] {
+        EXAMPLES
+    }
+
+    fn input_checks(&self) -> &'static [Check] {
+        &[Check::Chars("#.")]
+    }
 }
 
 #[derive(Debug, Clone, Copy, PartialEq)]
@@ -22,15 +32,22 @@ enum Space {
     Rock,
 }
 
-fn parse_maps(lines: &[String]) -> Vec<Map<Space>> {
-    let map_line_split = lines.split(|line| line.is_empty());
-
-    map_line_split.map(|map_lines| Map::new(map_lines, |c, _|
-        match c {
-            '.' => Space::Ash,
-            '#' => Space::Rock,
-            _ => panic!("Got unexpected char '{c}' when trying to parse map. Expected map to only consist of '.' or '#'")
-    })).collect()
+fn parse_maps(input: &InputText) -> Result<Vec<Map<Space>>> {
+    input
+        .sections()
+        .map(|section| {
+            let lines = section.lines().collect::<Vec<&str>>();
+            Map::try_new(&lines, |c, pos| match c {
+                '.' => Ok(Space::Ash),
+                '#' => Ok(Space::Rock),
+                _ => Err(Error::parse_at(
+                    section.start + pos.y as usize,
+                    pos.x as usize,
+                    format!("expected '.' or '#', got '{c}'"),
+                )),
+            })
+        })
+        .collect()
 }
 
 fn num_row_differences(row1: usize, row2: usize, map: &Map<Space>) -> usize {
@@ -108,10 +125,33 @@ fn part2(maps: &[Map<Space>]) -> usize {
     maps.iter().map(|map| find_best_reflection(map, true)).sum()
 }
 
+const EXAMPLE: &str = r#"#.##..##.
+..#.##.#.
+##......#
+##......#
+..#.##.#.
+..##..##.
+#.#.##.#.
+
+#...##..#
+#....#..#
+..##..###
+#####.##.
+#####.##.
+..##..###
+#....#..#"#;
+
+const EXAMPLES: &[Example] = &[Example {
+    name: "example",
+    input: EXAMPLE,
+    part1: Some("405"),
+    part2: Some("400"),
+    params: &[],
+}];
+
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::library::*;
 
     #[test]
     fn test_part1() -> Result<(), String> {
@@ -129,23 +169,18 @@ mod tests {
         Ok(())
     }
 
-    fn get_input<'a>() -> Vec<Map<Space>> {
-        let input = r#"#.##..##.
-..#.##.#.
-##......#
-##......#
-..#.##.#.
-..##..##.
-#.#.##.#.
-
-#...##..#
-#....#..#
-..##..###
-#####.##.
-#####.##.
-..##..###
-#....#..#"#;
+    fn get_input() -> Vec<Map<Space>> {
+        parse_maps(&InputText::from(EXAMPLE)).unwrap()
+    }
 
-        parse_maps(&parse_lines(&input))
+    #[test]
+    fn test_parse_error() {
+        let error = parse_maps(&InputText::from("#.#\n.#.\n\n##.\n#?#"))
+            .err()
+            .unwrap();
+        assert_eq!(
+            error.to_string(),
+            "line 5, column 2: expected '.' or '#', got '?'"
+        );
     }
 }
@@ -4,21 +4,30 @@ use std::{
 };
 
 use super::{DOWN, Dir, LEFT, Pos, RIGHT, UP};
-use crate::years::AdventDay;
+use crate::error::{Error, Result};
+use crate::library::validate::Check;
+use crate::years::{AdventDay, Context, Example, Solution, Solver};
 
 pub struct Day14 {}
 
 impl AdventDay for Day14 {
-    fn solve(&self) {
-        let lines = self.get_input();
-        let (map, rocks) = parse_map(&lines);
-        println!("Part1 solution: {}", part1(&map, &rocks));
-        println!("Part2 solution: {}", part2(&map, &rocks));
+    fn solve(&self, lines: &[String], ctx: &Context) -> Result<Solution> {
+        Solver::try_parse(ctx, || parse_map(lines))?
+            .part1(|(map, rocks)| part1(map, rocks))
+            .part2(|(map, rocks)| part2(map, rocks))
     }
 
     fn get_input_path(&self) -> &str {
         "Inputs/2023/day14.txt"
     }
+
+    fn examples(&self) -> &'static [Example] {
+        EXAMPLES
+    }
+
+    fn input_checks(&self) -> &'static [Check] {
+        &[Check::Chars(".#O"), Check::SquareGrid]
+    }
 }
 
 type Rock = Pos;
@@ -31,15 +40,24 @@ struct Map {
     rows: i32,
 }
 
-fn parse_map(lines: &[String]) -> (Map, Vec<Rock>) {
+fn parse_map(lines: &[String]) -> Result<(Map, Vec<Rock>)> {
     let mut rocks = Vec::new();
     let mut pillars = Vec::new();
 
     let rows = lines.len() as i32;
-    let cols = lines[0].len() as i32;
+    let cols = lines.first().map_or(0, |line| line.len()) as i32;
 
     for (row, line) in lines.iter().enumerate() {
-        for (col, c) in line.chars().enumerate() {
+        if line.len() != cols as usize {
+            return Err(Error::parse(
+                row,
+                format!(
+                    "expected {cols} columns like the first line, got {}",
+                    line.len()
+                ),
+            ));
+        }
+        for (col, c) in line.char_indices() {
             let pos = Pos {
                 x: col as i32,
                 y: row as i32,
@@ -47,7 +65,14 @@ fn parse_map(lines: &[String]) -> (Map, Vec<Rock>) {
             match c {
                 'O' => rocks.push(pos),
                 '#' => pillars.push(pos),
-                _ => {}
+                '.' => {}
+                c => {
+                    return Err(Error::parse_at(
+                        row,
+                        col,
+                        format!("expected 'O', '#' or '.', got '{c}'"),
+                    ));
+                }
             }
         }
     }
@@ -105,7 +130,7 @@ fn parse_map(lines: &[String]) -> (Map, Vec<Rock>) {
         rows,
     };
 
-    (map, rocks)
+    Ok((map, rocks))
 }
 
 fn calculate_load(rocks: &[Rock], map: &Map) -> usize {
@@ -237,6 +262,25 @@ fn part2(map: &Map, initial_rocks: &[Rock]) -> usize {
     calculate_load(final_rock_positions, map)
 }
 
+const EXAMPLE: &str = r#"O....#....
+O.OO#....#
+.....##...
+OO.#O....O
+.O.....O#.
+O.#..O.#.#
+..O..#O..O
+.......O..
+#....###..
+#OO..#...."#;
+
+const EXAMPLES: &[Example] = &[Example {
+    name: "example",
+    input: EXAMPLE,
+    part1: Some("136"),
+    part2: Some("64"),
+    params: &[],
+}];
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -258,18 +302,23 @@ mod tests {
         Ok(())
     }
 
-    fn get_input<'a>() -> (Map, Vec<Rock>) {
-        let input = r#"O....#....
-O.OO#....#
-.....##...
-OO.#O....O
-.O.....O#.
-O.#..O.#.#
-..O..#O..O
-.......O..
-#....###..
-#OO..#...."#;
+    fn get_input() -> (Map, Vec<Rock>) {
+        parse_map(&parse_lines(EXAMPLE)).unwrap()
+    }
+
+    #[test]
+    fn test_parse_error() {
+        let error = parse_map(&parse_lines("O..#\n.#o.")).err().unwrap();
+        assert!(matches!(
+            error,
+            Error::Parse {
+                line: 2,
+                column: Some(3),
+                ..
+            }
+        ));
 
-        parse_map(&parse_lines(&input))
+        let error = parse_map(&parse_lines("O..#\n.#.")).err().unwrap();
+        assert!(matches!(error, Error::Parse { line: 2, .. }));
     }
 }
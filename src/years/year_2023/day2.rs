@@ -1,18 +1,30 @@
-use crate::years::AdventDay;
+use crate::error::{Error, Result, offset_in};
+use crate::library::parse::{key_value, parse_number};
+use crate::library::validate::Check;
+use crate::years::{AdventDay, Context, Example, Solution, Solver};
 
 pub struct Day2 {}
 
 impl AdventDay for Day2 {
-    fn solve(&self) {
-        let lines = self.get_input();
-        let games = lines.iter().map(|line| parse_game(line)).collect::<Games>();
-        println!("Part1 solution: {}", part1(&games));
-        println!("Part2 solution: {}", part2(&games));
+    fn solve(&self, lines: &[String], ctx: &Context) -> Result<Solution> {
+        Solver::try_parse(ctx, || parse_games(lines))?
+            .part1(|games| part1(games))
+            .part2(|games| part2(games))
     }
 
     fn get_input_path(&self) -> &str {
         "Inputs/2023/day2.txt"
     }
+
+    fn examples(&self) -> &'static [Example] {
+        EXAMPLES
+    }
+
+    fn input_checks(&self) -> &'static [Check] {
+        &[Check::Lines(
+            r"Game \d+: \d+ (red|green|blue)((, |; )\d+ (red|green|blue))*",
+        )]
+    }
 }
 
 struct Reveal {
@@ -28,7 +40,7 @@ struct Game {
 }
 type Games = Vec<Game>;
 
-fn parse_reveals(reveal_str: &str) -> Reveals {
+fn parse_reveals(line: &str, reveal_str: &str, line_idx: usize) -> Result<Reveals> {
     let parse_reveal = |reveal_str: &str| {
         let mut reveal = Reveal {
             blue_cubes: 0,
@@ -37,47 +49,54 @@ fn parse_reveals(reveal_str: &str) -> Reveals {
         };
 
         for subset in reveal_str.split(", ") {
-            let mut colored_balls = subset.split_ascii_whitespace();
-            let num = colored_balls.next().unwrap().parse::<u32>().unwrap();
-            let color = colored_balls.last().unwrap();
+            let subset = subset.trim();
+            let Some((num, color)) = subset.split_once(' ') else {
+                return Err(Error::parse_at(
+                    line_idx,
+                    offset_in(line, subset),
+                    format!("expected '<number> <color>', got '{subset}'"),
+                ));
+            };
+            let num = parse_number(line, num, line_idx)?;
 
             match color {
                 "blue" => reveal.blue_cubes = num,
                 "red" => reveal.red_cubes = num,
                 "green" => reveal.green_cubes = num,
-                _ => panic!("Invalid color"),
+                _ => {
+                    return Err(Error::parse_at(
+                        line_idx,
+                        offset_in(line, color),
+                        format!("expected 'red', 'green' or 'blue', got '{color}'"),
+                    ));
+                }
             }
         }
 
-        reveal
+        Ok(reveal)
     };
 
-    let mut reveals = Reveals::new();
+    reveal_str.split(';').map(parse_reveal).collect()
+}
 
-    for reveal in reveal_str.split(';') {
-        reveals.push(parse_reveal(reveal));
-    }
+fn parse_game(line: &str, line_idx: usize) -> Result<Game> {
+    let (game, reveals) = key_value(line, line_idx)?;
+    let Some(game_id) = game.strip_prefix("Game ") else {
+        return Err(Error::parse_at(line_idx, 0, "expected 'Game <id>'"));
+    };
 
-    reveals
+    Ok(Game {
+        id: parse_number(line, game_id, line_idx)?,
+        reveals: parse_reveals(line, reveals, line_idx)?,
+    })
 }
 
-fn parse_game(line: &str) -> Game {
-    let mut game_str = line.split(':');
-    let game_id = game_str
-        .next()
-        .unwrap()
-        .split_ascii_whitespace()
-        .last()
-        .unwrap()
-        .parse::<u32>()
-        .unwrap();
-
-    let reveals = parse_reveals(game_str.next().unwrap_or(""));
-
-    Game {
-        id: game_id,
-        reveals,
-    }
+fn parse_games(lines: &[String]) -> Result<Games> {
+    lines
+        .iter()
+        .enumerate()
+        .map(|(line_idx, line)| parse_game(line, line_idx))
+        .collect()
 }
 
 fn game_is_possible(game: &Game) -> bool {
@@ -119,22 +138,27 @@ fn part2(games: &[Game]) -> u32 {
         .sum()
 }
 
+const EXAMPLE: &str = r#"Game 1: 3 blue, 4 red; 1 red, 2 green, 6 blue; 2 green
+Game 2: 1 blue, 2 green; 3 green, 4 blue, 1 red; 1 green, 1 blue
+Game 3: 8 green, 6 blue, 20 red; 5 blue, 4 red, 13 green; 5 green, 1 red
+Game 4: 1 green, 3 red, 6 blue; 3 green, 6 red; 3 green, 15 blue, 14 red
+Game 5: 6 red, 1 blue, 3 green; 2 blue, 1 red, 2 green"#;
+
+const EXAMPLES: &[Example] = &[Example {
+    name: "example",
+    input: EXAMPLE,
+    part1: Some("8"),
+    part2: Some("2286"),
+    params: &[],
+}];
+
 #[cfg(test)]
 mod tests {
     use super::*;
     use crate::library::*;
 
     fn get_games() -> Games {
-        let input = r#"Game 1: 3 blue, 4 red; 1 red, 2 green, 6 blue; 2 green
-Game 2: 1 blue, 2 green; 3 green, 4 blue, 1 red; 1 green, 1 blue
-Game 3: 8 green, 6 blue, 20 red; 5 blue, 4 red, 13 green; 5 green, 1 red
-Game 4: 1 green, 3 red, 6 blue; 3 green, 6 red; 3 green, 15 blue, 14 red
-Game 5: 6 red, 1 blue, 3 green; 2 blue, 1 red, 2 green"#;
-
-        parse_lines(&input)
-            .iter()
-            .map(|line| parse_game(line))
-            .collect::<Games>()
+        parse_games(&parse_lines(EXAMPLE)).unwrap()
     }
 
     #[test]
@@ -152,4 +176,18 @@ Game 5: 6 red, 1 blue, 3 green; 2 blue, 1 red, 2 green"#;
 
         Ok(())
     }
+
+    #[test]
+    fn test_parse_error() {
+        let lines = parse_lines("Game 1: 3 blue, 4 red\nGame 2: 1 blue; 2 purple");
+        let error = parse_games(&lines).err().unwrap();
+        assert!(matches!(
+            error,
+            Error::Parse {
+                line: 2,
+                column: Some(19),
+                ..
+            }
+        ));
+    }
 }
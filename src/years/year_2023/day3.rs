@@ -1,23 +1,35 @@
 use crate::{
-    dir::{DIAGONALS, DIRECTIONS},
-    pos::Pos,
-    years::AdventDay,
+    error::Result,
+    library::{
+        dir::{DIAGONALS, DIRECTIONS},
+        parse::parse_number,
+        pos::Pos,
+        validate::Check,
+    },
+    years::{AdventDay, Context, Example, Solution, Solver},
 };
 use std::ops::Range;
 
 pub struct Day3 {}
 
 impl AdventDay for Day3 {
-    fn solve(&self) {
-        let lines = self.get_input();
-        let schematic = parse_schematic(&lines);
-        println!("Part1 solution: {}", part1(&schematic));
-        println!("Part2 solution: {}", part2(&schematic));
+    fn solve(&self, lines: &[String], ctx: &Context) -> Result<Solution> {
+        Solver::try_parse(ctx, || parse_schematic(lines))?
+            .part1(part1)
+            .part2(part2)
     }
 
     fn get_input_path(&self) -> &str {
         "Inputs/2023/day3.txt"
     }
+
+    fn examples(&self) -> &'static [Example] {
+        EXAMPLES
+    }
+
+    fn input_checks(&self) -> &'static [Check] {
+        &[Check::SquareGrid]
+    }
 }
 
 #[derive(Debug)]
@@ -39,27 +51,25 @@ struct Schematic {
     symbols: Vec<Symbol>,
 }
 
-fn parse_schematic(lines: &[String]) -> Schematic {
+fn parse_part(line: &str, cols: Range<usize>, row: usize) -> Result<SchematicNumber> {
+    Ok(SchematicNumber {
+        num: parse_number(line, &line[cols.clone()], row)?,
+        row: row as i32,
+        col: cols.start as i32..cols.end as i32,
+    })
+}
+
+fn parse_schematic(lines: &[String]) -> Result<Schematic> {
     let mut parts = Vec::new();
     let mut symbols = Vec::new();
 
     for (row, line) in lines.iter().enumerate() {
-        let mut number = String::new();
         let mut start_idx = None;
-        for (col, char) in line.chars().enumerate() {
+        for (col, char) in line.char_indices() {
             if char.is_ascii_digit() {
-                if start_idx.is_none() {
-                    start_idx = Some(col as i32);
-                }
-                number.push(char);
-            } else if !number.is_empty() {
-                parts.push(SchematicNumber {
-                    num: number.parse().unwrap(),
-                    row: row as i32,
-                    col: start_idx.unwrap()..col as i32,
-                });
-                number.clear();
-                start_idx = None;
+                start_idx.get_or_insert(col);
+            } else if let Some(start) = start_idx.take() {
+                parts.push(parse_part(line, start..col, row)?);
             }
             if !char.is_ascii_digit() && char != '.' {
                 symbols.push(Symbol {
@@ -72,15 +82,11 @@ fn parse_schematic(lines: &[String]) -> Schematic {
             }
         }
         if let Some(start) = start_idx {
-            parts.push(SchematicNumber {
-                num: number.parse().unwrap(),
-                row: row as i32,
-                col: start..line.len() as i32,
-            });
+            parts.push(parse_part(line, start..line.len(), row)?);
         }
     }
 
-    Schematic { parts, symbols }
+    Ok(Schematic { parts, symbols })
 }
 
 fn get_numbers_around_pos<'a>(
@@ -138,13 +144,7 @@ fn part2(schematic: &Schematic) -> u32 {
         .sum()
 }
 
-#[cfg(test)]
-mod tests {
-    use super::*;
-    use crate::library::*;
-
-    fn get_input() -> Schematic {
-        let input = r#"467..114..
+const EXAMPLE: &str = r#"467..114..
 ...*......
 ..35..633.
 ......#...
@@ -155,7 +155,22 @@ mod tests {
 ...$.*....
 .664.598.."#;
 
-        parse_schematic(&parse_lines(&input))
+const EXAMPLES: &[Example] = &[Example {
+    name: "example",
+    input: EXAMPLE,
+    part1: Some("4361"),
+    part2: Some("467835"),
+    params: &[],
+}];
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::error::Error;
+    use crate::library::*;
+
+    fn get_input() -> Schematic {
+        parse_schematic(&parse_lines(EXAMPLE)).unwrap()
     }
 
     #[test]
@@ -173,4 +188,18 @@ mod tests {
 
         Ok(())
     }
+
+    #[test]
+    fn test_parse_error() {
+        let lines = parse_lines("467..\n..*..\n.99999999999");
+        let error = parse_schematic(&lines).unwrap_err();
+        assert!(matches!(
+            error,
+            Error::Parse {
+                line: 3,
+                column: Some(2),
+                ..
+            }
+        ));
+    }
 }
@@ -1,17 +1,27 @@
-use crate::years::AdventDay;
+use crate::error::{Error, Result};
+use crate::library::parse::{key_value, parse_number};
+use crate::library::validate::Check;
+use crate::years::{AdventDay, Context, Example, Solution, Solver};
 pub struct Day4 {}
 
 impl AdventDay for Day4 {
-    fn solve(&self) {
-        let lines = self.get_input();
-        let games = parse_games(&lines);
-        println!("Part1 solution: {}", part1(&games));
-        println!("Part2 solution: {}", part2(&games));
+    fn solve(&self, lines: &[String], ctx: &Context) -> Result<Solution> {
+        Solver::try_parse(ctx, || parse_games(lines))?
+            .part1(|games| part1(games))
+            .part2(|games| part2(games))
     }
 
     fn get_input_path(&self) -> &str {
         "Inputs/2023/day4.txt"
     }
+
+    fn examples(&self) -> &'static [Example] {
+        EXAMPLES
+    }
+
+    fn input_checks(&self) -> &'static [Check] {
+        &[Check::Lines(r"Card +\d+:( +\d+)+ \|( +\d+)+")]
+    }
 }
 
 struct Game {
@@ -19,31 +29,34 @@ struct Game {
     numbers_you_have: Vec<u32>,
 }
 
-fn parse_game(line: &str) -> Game {
-    let mut parts = line.split(':').next_back().unwrap().split(" | ");
-
-    let winning_numbers = parts
-        .next()
-        .unwrap()
-        .split_ascii_whitespace()
-        .map(|num| num.parse::<u32>().unwrap())
-        .collect();
-
-    let numbers_you_have = parts
-        .next()
-        .unwrap()
+fn parse_numbers(line: &str, numbers: &str, line_idx: usize) -> Result<Vec<u32>> {
+    numbers
         .split_ascii_whitespace()
-        .map(|num| num.parse::<u32>().unwrap())
-        .collect();
+        .map(|num| parse_number(line, num, line_idx))
+        .collect()
+}
 
-    Game {
-        winning_numbers,
-        numbers_you_have,
-    }
+fn parse_game(line: &str, line_idx: usize) -> Result<Game> {
+    let (_, numbers) = key_value(line, line_idx)?;
+    let Some((winning_numbers, numbers_you_have)) = numbers.split_once(" | ") else {
+        return Err(Error::parse(
+            line_idx,
+            "expected '<winning numbers> | <numbers you have>'",
+        ));
+    };
+
+    Ok(Game {
+        winning_numbers: parse_numbers(line, winning_numbers, line_idx)?,
+        numbers_you_have: parse_numbers(line, numbers_you_have, line_idx)?,
+    })
 }
 
-fn parse_games(lines: &[String]) -> Vec<Game> {
-    lines.iter().map(|line| parse_game(line)).collect()
+fn parse_games(lines: &[String]) -> Result<Vec<Game>> {
+    lines
+        .iter()
+        .enumerate()
+        .map(|(line_idx, line)| parse_game(line, line_idx))
+        .collect()
 }
 
 fn get_winning_numbers(game: &Game) -> u32 {
@@ -80,20 +93,28 @@ fn part2(games: &[Game]) -> u32 {
     scratchcards.iter().sum::<u32>()
 }
 
-#[cfg(test)]
-mod tests {
-    use super::*;
-    use crate::library::*;
-
-    fn get_lines() -> Vec<Game> {
-        let input = r#"Card 1: 41 48 83 86 17 | 83 86  6 31 17  9 48 53
+const EXAMPLE: &str = r#"Card 1: 41 48 83 86 17 | 83 86  6 31 17  9 48 53
 Card 2: 13 32 20 16 61 | 61 30 68 82 17 32 24 19
 Card 3:  1 21 53 59 44 | 69 82 63 72 16 21 14  1
 Card 4: 41 92 73 84 69 | 59 84 76 51 58  5 54 83
 Card 5: 87 83 26 28 32 | 88 30 70 12 93 22 82 36
 Card 6: 31 18 13 56 72 | 74 77 10 23 35 67 36 11"#;
 
-        parse_games(&parse_lines(&input))
+const EXAMPLES: &[Example] = &[Example {
+    name: "example",
+    input: EXAMPLE,
+    part1: Some("13"),
+    part2: Some("30"),
+    params: &[],
+}];
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::library::*;
+
+    fn get_lines() -> Vec<Game> {
+        parse_games(&parse_lines(EXAMPLE)).unwrap()
     }
 
     #[test]
@@ -111,4 +132,18 @@ Card 6: 31 18 13 56 72 | 74 77 10 23 35 67 36 11"#;
 
         Ok(())
     }
+
+    #[test]
+    fn test_parse_error() {
+        let lines = parse_lines("Card 1: 41 48 | 83 86\nCard 2: 13 32 | 61 3o");
+        let error = parse_games(&lines).err().unwrap();
+        assert!(matches!(
+            error,
+            Error::Parse {
+                line: 2,
+                column: Some(20),
+                ..
+            }
+        ));
+    }
 }
@@ -1,19 +1,32 @@
 use std::collections::VecDeque;
 
-use crate::years::AdventDay;
+use crate::error::{Error, Result};
+use crate::library::parse::parse_number;
+use crate::library::text::InputText;
+use crate::library::validate::Check;
+use crate::years::{AdventDay, Context, Example, Solution, Solver};
 pub struct Day5 {}
 
 impl AdventDay for Day5 {
-    fn solve(&self) {
-        let lines = self.get_input();
-        let (seed_ranges, range_maps) = parse_input(&lines);
-        println!("Part1 solution: {}", part1(&seed_ranges, &range_maps));
-        println!("Part2 solution: {}", part2(&seed_ranges, &range_maps));
+    fn solve_input(&self, input: &InputText, ctx: &Context) -> Result<Solution> {
+        Solver::try_parse(ctx, || parse_input(input))?
+            .part1(|(seed_ranges, range_maps)| part1(seed_ranges, range_maps))
+            .part2(|(seed_ranges, range_maps)| part2(seed_ranges, range_maps))
     }
 
     fn get_input_path(&self) -> &str {
         "Inputs/2023/day5.txt"
     }
+
+    fn examples(&self) -> &'static [Example] {
+        EXAMPLES
+    }
+
+    fn input_checks(&self) -> &'static [Check] {
+        &[Check::Lines(
+            r"seeds:( \d+)+|[a-z]+-to-[a-z]+ map:|\d+ \d+ \d+",
+        )]
+    }
 }
 
 #[derive(Debug, Clone)]
@@ -155,65 +168,58 @@ struct SeedRange {
     end: u64,
 }
 
-fn parse_input(lines: &[String]) -> (Vec<SeedRange>, Vec<Vec<RangeMapping>>) {
-    let mut seed_ranges = Vec::new();
-    let mut range_maps = Vec::new();
-
-    let mut seed_ranges_iter = lines.iter();
-    let range_maps_iter = lines.iter().skip(2);
-
-    if let Some(line) = seed_ranges_iter.next() {
-        let parts = line
-            .split(':')
-            .next_back()
-            .expect("Expected seed ranges")
-            .split_ascii_whitespace();
-        for part in parts {
-            let start = part.parse().expect("Expected a number");
-            seed_ranges.push(SeedRange { start, end: start });
-        }
+type Almanac = (Vec<SeedRange>, Vec<Vec<RangeMapping>>);
+
+fn parse_seeds(line: &str, line_idx: usize) -> Result<Vec<SeedRange>> {
+    let Some(seeds) = line.strip_prefix("seeds:") else {
+        return Err(Error::parse(line_idx, "expected 'seeds: <n> <n> ...'"));
+    };
+    seeds
+        .split_ascii_whitespace()
+        .map(|seed| {
+            let start = parse_number(line, seed, line_idx)?;
+            Ok(SeedRange { start, end: start })
+        })
+        .collect()
+}
+
+fn parse_mapping(line: &str, line_idx: usize) -> Result<RangeMapping> {
+    match line.split_ascii_whitespace().collect::<Vec<&str>>()[..] {
+        [destination_start, source_start, length] => Ok(RangeMapping {
+            length: parse_number(line, length, line_idx)?,
+            destination_start: parse_number(line, destination_start, line_idx)?,
+            source_start: parse_number(line, source_start, line_idx)?,
+        }),
+        _ => Err(Error::parse(
+            line_idx,
+            "expected '<destination start> <source start> <length>'",
+        )),
     }
+}
 
-    let mut ranges = Vec::new();
-    for line in range_maps_iter {
-        if line.is_empty() {
-            range_maps.push(ranges);
-            ranges = Vec::new();
-            continue;
-        }
-        if line.ends_with(':') {
-            continue;
-        }
+fn parse_input(input: &InputText) -> Result<Almanac> {
+    let mut sections = input.sections();
+    let Some(seeds) = sections.next() else {
+        return Err(Error::parse(0, "expected 'seeds: <n> <n> ...'"));
+    };
 
-        let mut parts = line.split_ascii_whitespace();
-        let destination_start = parts
-            .next()
-            .expect("Expected a start of mapping destination")
-            .parse()
-            .expect("Expected a number");
-        let source_start = parts
-            .next()
-            .expect("Expected an start of mapping source")
-            .parse()
-            .expect("Expected a number");
-        let length = parts
-            .next()
-            .expect("Expected length of mapping table")
-            .parse()
-            .expect("Expected a number");
-
-        ranges.push(RangeMapping {
-            length,
-            destination_start,
-            source_start,
-        });
+    let mut seed_ranges = Vec::new();
+    for (line_idx, line) in seeds.indexed() {
+        seed_ranges.extend(parse_seeds(line, line_idx)?);
     }
 
-    if !ranges.is_empty() {
-        range_maps.push(ranges);
-    }
+    // Every map starts with a "<source>-to-<destination> map:" header
+    let range_maps = sections
+        .map(|section| {
+            section
+                .indexed()
+                .filter(|(_, line)| !line.ends_with(':'))
+                .map(|(line_idx, line)| parse_mapping(line, line_idx))
+                .collect()
+        })
+        .collect::<Result<_>>()?;
 
-    (seed_ranges, range_maps)
+    Ok((seed_ranges, range_maps))
 }
 
 fn map_ranges(seed_ranges: &[SeedRange], range_maps: &[RangeMapping]) -> Vec<SeedRange> {
@@ -289,13 +295,7 @@ fn part2(input_seed_ranges: &[SeedRange], range_maps: &[Vec<RangeMapping>]) -> u
     part1(&seed_ranges, &range_maps)
 }
 
-#[cfg(test)]
-mod tests {
-    use super::*;
-    use crate::library::*;
-
-    fn get_lines() -> (Vec<SeedRange>, Vec<Vec<RangeMapping>>) {
-        let input = r#"seeds: 79 14 55 13
+const EXAMPLE: &str = r#"seeds: 79 14 55 13
 
 seed-to-soil map:
 50 98 2
@@ -329,7 +329,41 @@ humidity-to-location map:
 60 56 37
 56 93 4"#;
 
-        parse_input(&parse_lines(&input))
+const EXAMPLES: &[Example] = &[Example {
+    name: "example",
+    input: EXAMPLE,
+    part1: Some("35"),
+    part2: Some("46"),
+    params: &[],
+}];
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn get_lines() -> Almanac {
+        parse_input(&InputText::from(EXAMPLE)).unwrap()
+    }
+
+    #[test]
+    fn test_parse_error() {
+        let input = InputText::from("seeds: 79 14\n\nseed-to-soil map:\n50 98\n52 x 48");
+        let error = parse_input(&input).unwrap_err();
+        assert!(matches!(error, Error::Parse { line: 4, .. }), "{error}");
+
+        let input = InputText::from("seeds: 79 14\n\nseed-to-soil map:\n52 x 48");
+        let error = parse_input(&input).unwrap_err();
+        assert!(
+            matches!(
+                error,
+                Error::Parse {
+                    line: 4,
+                    column: Some(4),
+                    ..
+                }
+            ),
+            "{error}"
+        );
     }
 
     #[test]
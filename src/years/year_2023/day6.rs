@@ -1,17 +1,27 @@
-use crate::years::AdventDay;
+use crate::error::{Error, Result};
+use crate::library::parse::{key_value, parse_number};
+use crate::library::validate::Check;
+use crate::years::{AdventDay, Context, Example, Solution, Solver};
 pub struct Day6 {}
 
 impl AdventDay for Day6 {
-    fn solve(&self) {
-        let lines = self.get_input();
-        let races = parse_input(&lines);
-        println!("Part1 solution: {}", part1(&races));
-        println!("Part2 solution: {}", part2(&races));
+    fn solve(&self, lines: &[String], ctx: &Context) -> Result<Solution> {
+        Solver::try_parse(ctx, || parse_input(lines))?
+            .part1(|races| part1(races))
+            .part2(|races| part2(races))
     }
 
     fn get_input_path(&self) -> &str {
         "Inputs/2023/day6.txt"
     }
+
+    fn examples(&self) -> &'static [Example] {
+        EXAMPLES
+    }
+
+    fn input_checks(&self) -> &'static [Check] {
+        &[Check::Lines(r"Time:( +\d+)+|Distance:( +\d+)+")]
+    }
 }
 
 #[derive(Debug)]
@@ -20,32 +30,50 @@ struct Race {
     distance_to_beat: u64,
 }
 
-fn parse_input(lines: &[String]) -> Vec<Race> {
-    let race_times = lines
-        .iter()
-        .next()
-        .expect("Line with times is required")
-        .split_ascii_whitespace()
-        .skip(1) // Skip the "Time:" header
-        .filter_map(|s| s.parse().ok())
-        .collect::<Vec<u64>>();
+fn parse_row(lines: &[String], line_idx: usize, header: &str) -> Result<Vec<u64>> {
+    let Some(line) = lines.get(line_idx) else {
+        return Err(Error::parse(
+            line_idx,
+            format!("expected a '{header}:' line"),
+        ));
+    };
+    let (key, values) = key_value(line, line_idx)?;
+    if key != header {
+        return Err(Error::parse_at(
+            line_idx,
+            0,
+            format!("expected '{header}:', got '{key}:'"),
+        ));
+    }
 
-    let distances_to_beat = lines
-        .get(1)
-        .expect("Line with distances is required")
+    values
         .split_ascii_whitespace()
-        .skip(1) // Skip the "Distance:" header
-        .filter_map(|s| s.parse().ok())
-        .collect::<Vec<u64>>();
+        .map(|value| parse_number(line, value, line_idx))
+        .collect()
+}
+
+fn parse_input(lines: &[String]) -> Result<Vec<Race>> {
+    let race_times = parse_row(lines, 0, "Time")?;
+    let distances_to_beat = parse_row(lines, 1, "Distance")?;
+    if race_times.len() != distances_to_beat.len() {
+        return Err(Error::parse(
+            1,
+            format!(
+                "expected {} distances, one for each time, got {}",
+                race_times.len(),
+                distances_to_beat.len()
+            ),
+        ));
+    }
 
-    race_times
+    Ok(race_times
         .iter()
         .zip(distances_to_beat.iter())
         .map(|(time, distance)| Race {
             time: *time,
             distance_to_beat: *distance,
         })
-        .collect()
+        .collect())
 }
 
 fn solve_race(race: &Race) -> u64 {
@@ -76,16 +104,24 @@ fn part2(races: &[Race]) -> u64 {
     })
 }
 
+const EXAMPLE: &str = r#"Time:      7  15   30
+Distance:  9  40  200"#;
+
+const EXAMPLES: &[Example] = &[Example {
+    name: "example",
+    input: EXAMPLE,
+    part1: Some("288"),
+    part2: Some("71503"),
+    params: &[],
+}];
+
 #[cfg(test)]
 mod tests {
     use super::*;
     use crate::library::*;
 
     fn get_lines() -> Vec<Race> {
-        let input = r#"Time:      7  15   30
-Distance:  9  40  200"#;
-
-        parse_input(&parse_lines(&input))
+        parse_input(&parse_lines(EXAMPLE)).unwrap()
     }
 
     #[test]
@@ -103,4 +139,22 @@ Distance:  9  40  200"#;
 
         Ok(())
     }
+
+    #[test]
+    fn test_parse_error() {
+        let lines = parse_lines("Time:      7  15   30\nDistance:  9  4o  200");
+        let error = parse_input(&lines).unwrap_err();
+        assert!(matches!(
+            error,
+            Error::Parse {
+                line: 2,
+                column: Some(15),
+                ..
+            }
+        ));
+
+        let lines = parse_lines("Time:      7  15   30\nDistance:  9  40");
+        let error = parse_input(&lines).unwrap_err();
+        assert!(matches!(error, Error::Parse { line: 2, .. }));
+    }
 }
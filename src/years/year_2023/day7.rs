@@ -1,19 +1,29 @@
-use crate::years::AdventDay;
+use crate::error::{Error, Result, offset_in};
+use crate::library::parse::parse_number;
+use crate::library::validate::Check;
+use crate::years::{AdventDay, Context, Example, Solution, Solver};
 
 use std::{cmp::Ordering, collections::HashMap};
 pub struct Day7 {}
 
 impl AdventDay for Day7 {
-    fn solve(&self) {
-        let lines = self.get_input();
-        let hands = parse_input(&lines);
-        println!("Part1 solution: {}", part1(&hands));
-        println!("Part2 solution: {}", part2(&hands));
+    fn solve(&self, lines: &[String], ctx: &Context) -> Result<Solution> {
+        Solver::try_parse(ctx, || parse_input(lines))?
+            .part1(|hands| part1(hands))
+            .part2(|hands| part2(hands))
     }
 
     fn get_input_path(&self) -> &str {
         "Inputs/2023/day7.txt"
     }
+
+    fn examples(&self) -> &'static [Example] {
+        EXAMPLES
+    }
+
+    fn input_checks(&self) -> &'static [Check] {
+        &[Check::Lines(r"[2-9TJQKA]{5} \d+")]
+    }
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
@@ -83,40 +93,50 @@ fn parse_strength(cards: &[Card]) -> Strength {
     }
 }
 
-fn parse_rank(c: char) -> Card {
-    if c.is_ascii_digit() {
-        Card(c.to_digit(10).unwrap() as u8)
-    } else {
-        match c {
-            'T' => Card(10),
-            'J' => Card(11),
-            'Q' => Card(12),
-            'K' => Card(13),
-            'A' => Card(14),
-            _ => panic!("Invalid card rank"),
-        }
+fn parse_rank(c: char) -> Option<Card> {
+    match c {
+        '2'..='9' => c.to_digit(10).map(|rank| Card(rank as u8)),
+        'T' => Some(Card(10)),
+        'J' => Some(Card(11)),
+        'Q' => Some(Card(12)),
+        'K' => Some(Card(13)),
+        'A' => Some(Card(14)),
+        _ => None,
     }
 }
 
-fn parse_input(lines: &[String]) -> Vec<Hand> {
+fn parse_hand(line: &str, line_idx: usize) -> Result<Hand> {
+    let Some((hand_str, bid)) = line.split_once(' ') else {
+        return Err(Error::parse(line_idx, "expected '<cards> <bid>'"));
+    };
+
+    let mut cards = Vec::with_capacity(5);
+    for (col_idx, c) in hand_str.char_indices() {
+        let card = parse_rank(c).ok_or_else(|| {
+            Error::parse_at(line_idx, col_idx, format!("invalid card rank '{c}'"))
+        })?;
+        cards.push(card);
+    }
+    let cards: [Card; 5] = cards.try_into().map_err(|cards: Vec<Card>| {
+        Error::parse_at(
+            line_idx,
+            offset_in(line, hand_str),
+            format!("expected 5 cards, got {}", cards.len()),
+        )
+    })?;
+
+    Ok(Hand {
+        cards,
+        strength: parse_strength(&cards),
+        bid: parse_number(line, bid.trim(), line_idx)?,
+    })
+}
+
+fn parse_input(lines: &[String]) -> Result<Vec<Hand>> {
     lines
         .iter()
-        .map(|line| {
-            let mut parts = line.split_ascii_whitespace();
-            let hand_str = parts.next().expect("Hand of cards");
-            let cards: [Card; 5] =
-                core::array::from_fn(|i| parse_rank(hand_str.chars().nth(i).unwrap()));
-            let bid = parts
-                .next()
-                .expect("Expected a bid")
-                .parse()
-                .expect("Expected a numerical bid");
-            Hand {
-                cards,
-                strength: parse_strength(&cards),
-                bid,
-            }
-        })
+        .enumerate()
+        .map(|(line_idx, line)| parse_hand(line, line_idx))
         .collect()
 }
 
@@ -222,19 +242,46 @@ fn part2(hands: &[Hand]) -> usize {
     part1(&hands)
 }
 
+const EXAMPLE: &str = r#"32T3K 765
+T55J5 684
+KK677 28
+KTJJT 220
+QQQJA 483"#;
+
+const EXAMPLES: &[Example] = &[Example {
+    name: "example",
+    input: EXAMPLE,
+    part1: Some("6440"),
+    part2: Some("5905"),
+    params: &[],
+}];
+
 #[cfg(test)]
 mod tests {
     use super::*;
     use crate::library::*;
 
     fn get_lines() -> Vec<Hand> {
-        let input = r#"32T3K 765
-T55J5 684
-KK677 28
-KTJJT 220
-QQQJA 483"#;
+        parse_input(&parse_lines(EXAMPLE)).unwrap()
+    }
+
+    #[test]
+    fn test_parse_error() {
+        let error = parse_input(&parse_lines("32T3K 765\nT55X5 684")).unwrap_err();
+        assert!(
+            matches!(
+                error,
+                Error::Parse {
+                    line: 2,
+                    column: Some(4),
+                    ..
+                }
+            ),
+            "{error}"
+        );
 
-        parse_input(&parse_lines(&input))
+        let error = parse_input(&parse_lines("32T3 765")).unwrap_err();
+        assert!(matches!(error, Error::Parse { line: 1, .. }), "{error}");
     }
 
     #[test]
@@ -1,18 +1,29 @@
-use crate::{prime::PrimeFactorizationHelper, years::AdventDay};
+use crate::{
+    error::{Error, Result},
+    library::{prime::PrimeFactorizationHelper, validate::Check},
+    years::{AdventDay, Context, Example, Solution, Solver},
+};
 use std::collections::{HashMap, HashSet};
 pub struct Day8 {}
 
 impl AdventDay for Day8 {
-    fn solve(&self) {
-        let lines = self.get_input();
-        let (instructions, network) = parse_input(&lines);
-        println!("Part1 solution: {}", part1(&instructions, &network));
-        println!("Part2 solution: {}", part2(&instructions, &network));
+    fn solve(&self, lines: &[String], ctx: &Context) -> Result<Solution> {
+        Solver::try_parse(ctx, || parse_input(lines))?
+            .part1(|(instructions, network)| part1(instructions, network))
+            .part2(|(instructions, network)| part2(instructions, network))
     }
 
     fn get_input_path(&self) -> &str {
         "Inputs/2023/day8.txt"
     }
+
+    fn examples(&self) -> &'static [Example] {
+        EXAMPLES
+    }
+
+    fn input_checks(&self) -> &'static [Check] {
+        &[Check::Lines(r"[LR]+|\w{3} = \(\w{3}, \w{3}\)")]
+    }
 }
 
 #[derive(Debug)]
@@ -31,27 +42,49 @@ struct Turn {
 
 type Network = HashMap<Node, Turn>;
 
-fn parse_input(lines: &[String]) -> (Vec<Instruction>, Network) {
-    let instructions = lines
-        .first()
-        .expect("Instruction line")
-        .chars()
-        .map(|c| match c {
-            'L' => Instruction::Left,
-            'R' => Instruction::Right,
-            c => panic!("Got unexpected char '{c}' when trying to parse the instruction string"),
+fn parse_turn(line: &str, line_idx: usize) -> Result<(Node, Turn)> {
+    line.split_once(" = ")
+        .and_then(|(node, turn)| {
+            let (left, right) = turn
+                .strip_prefix('(')?
+                .strip_suffix(')')?
+                .split_once(", ")?;
+            Some((
+                node.to_owned(),
+                Turn {
+                    left: left.to_owned(),
+                    right: right.to_owned(),
+                },
+            ))
         })
-        .collect();
+        .ok_or_else(|| Error::parse(line_idx, "expected '<node> = (<left>, <right>)'"))
+}
 
-    let mut network = HashMap::new();
-    for line in lines.iter().skip(2) {
-        let node = line[0..3].to_owned();
-        let left = line[7..10].to_owned();
-        let right = line[12..15].to_owned();
-        network.insert(node, Turn { left, right });
-    }
+fn parse_input(lines: &[String]) -> Result<(Vec<Instruction>, Network)> {
+    let Some(instruction_line) = lines.first() else {
+        return Err(Error::parse(0, "expected a line of instructions"));
+    };
+    let instructions = instruction_line
+        .char_indices()
+        .map(|(col_idx, c)| match c {
+            'L' => Ok(Instruction::Left),
+            'R' => Ok(Instruction::Right),
+            c => Err(Error::parse_at(
+                0,
+                col_idx,
+                format!("expected 'L' or 'R', got '{c}'"),
+            )),
+        })
+        .collect::<Result<_>>()?;
+
+    let network = lines
+        .iter()
+        .enumerate()
+        .skip(2)
+        .map(|(line_idx, line)| parse_turn(line, line_idx))
+        .collect::<Result<_>>()?;
 
-    (instructions, network)
+    Ok((instructions, network))
 }
 
 fn steps_to_finishing_pos(
@@ -88,6 +121,10 @@ fn steps_to_finishing_pos(
 }
 
 fn part1(instructions: &[Instruction], network: &Network) -> usize {
+    if !network.contains_key("AAA") {
+        return 0;
+    }
+
     steps_to_finishing_pos(
         &["AAA".to_owned()],
         &["ZZZ".to_owned()],
@@ -110,13 +147,7 @@ fn part2(instructions: &[Instruction], network: &Network) -> usize {
     steps_to_finishing_pos(&starting_nodes, &end_nodes, instructions, network)
 }
 
-#[cfg(test)]
-mod tests {
-    use super::*;
-    use crate::library::*;
-
-    fn test_case_1() -> (Vec<Instruction>, Network) {
-        let input = r#"RL
+const EXAMPLE_1: &str = r#"RL
 
 AAA = (BBB, CCC)
 BBB = (DDD, EEE)
@@ -126,21 +157,13 @@ EEE = (EEE, EEE)
 GGG = (GGG, GGG)
 ZZZ = (ZZZ, ZZZ)"#;
 
-        parse_input(&parse_lines(&input))
-    }
-
-    fn test_case_2() -> (Vec<Instruction>, Network) {
-        let input = r#"LLR
+const EXAMPLE_2: &str = r#"LLR
 
 AAA = (BBB, BBB)
 BBB = (AAA, ZZZ)
 ZZZ = (ZZZ, ZZZ)"#;
 
-        parse_input(&parse_lines(&input))
-    }
-
-    fn test_case_3() -> (Vec<Instruction>, Network) {
-        let input = r#"LR
+const EXAMPLE_3: &str = r#"LR
 
 11A = (11B, XXX)
 11B = (XXX, 11Z)
@@ -151,7 +174,45 @@ ZZZ = (ZZZ, ZZZ)"#;
 22Z = (22B, 22B)
 XXX = (XXX, XXX)"#;
 
-        parse_input(&parse_lines(&input))
+const EXAMPLES: &[Example] = &[
+    Example {
+        name: "example1",
+        input: EXAMPLE_1,
+        part1: Some("2"),
+        part2: None,
+        params: &[],
+    },
+    Example {
+        name: "example2",
+        input: EXAMPLE_2,
+        part1: Some("6"),
+        part2: None,
+        params: &[],
+    },
+    Example {
+        name: "example3",
+        input: EXAMPLE_3,
+        part1: None,
+        part2: Some("6"),
+        params: &[],
+    },
+];
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::library::*;
+
+    fn test_case_1() -> (Vec<Instruction>, Network) {
+        parse_input(&parse_lines(EXAMPLE_1)).unwrap()
+    }
+
+    fn test_case_2() -> (Vec<Instruction>, Network) {
+        parse_input(&parse_lines(EXAMPLE_2)).unwrap()
+    }
+
+    fn test_case_3() -> (Vec<Instruction>, Network) {
+        parse_input(&parse_lines(EXAMPLE_3)).unwrap()
     }
 
     #[test]
@@ -177,4 +238,20 @@ XXX = (XXX, XXX)"#;
 
         Ok(())
     }
+
+    #[test]
+    fn test_parse_error() {
+        let error = parse_input(&parse_lines("LRX\n\nAAA = (BBB, CCC)")).unwrap_err();
+        assert!(matches!(
+            error,
+            Error::Parse {
+                line: 1,
+                column: Some(3),
+                ..
+            }
+        ));
+
+        let error = parse_input(&parse_lines("LR\n\nAAA = (BBB, CCC)\nBBB = CCC")).unwrap_err();
+        assert!(matches!(error, Error::Parse { line: 4, .. }));
+    }
 }
@@ -1,29 +1,47 @@
-use crate::years::AdventDay;
+use crate::error::{Error, Result};
+use crate::library::parse::parse_number;
+use crate::library::validate::Check;
+use crate::years::{AdventDay, Context, Example, Solution, Solver};
 pub struct Day9 {}
 
 impl AdventDay for Day9 {
-    fn solve(&self) {
-        let lines = self.get_input();
-        let histories = parse_input(&lines);
-        println!("Part1 solution: {}", part1(&histories));
-        println!("Part2 solution: {}", part2(&histories));
+    fn solve(&self, lines: &[String], ctx: &Context) -> Result<Solution> {
+        Solver::try_parse(ctx, || parse_input(lines))?
+            .part1(|histories| part1(histories))
+            .part2(|histories| part2(histories))
     }
 
     fn get_input_path(&self) -> &str {
         "Inputs/2023/day9.txt"
     }
+
+    fn examples(&self) -> &'static [Example] {
+        EXAMPLES
+    }
+
+    fn input_checks(&self) -> &'static [Check] {
+        &[Check::Lines(r"-?\d+( -?\d+)*")]
+    }
 }
 
 type History = Vec<i32>;
 
-fn parse_input(lines: &[String]) -> Vec<History> {
+fn parse_history(line: &str, line_idx: usize) -> Result<History> {
+    let history = line
+        .split_ascii_whitespace()
+        .map(|value| parse_number(line, value, line_idx))
+        .collect::<Result<History>>()?;
+    if history.is_empty() {
+        return Err(Error::parse(line_idx, "expected at least one value"));
+    }
+    Ok(history)
+}
+
+fn parse_input(lines: &[String]) -> Result<Vec<History>> {
     lines
         .iter()
-        .map(|line| {
-            line.split_ascii_whitespace()
-                .filter_map(|str| str.parse::<i32>().ok())
-                .collect()
-        })
+        .enumerate()
+        .map(|(line_idx, line)| parse_history(line, line_idx))
         .collect()
 }
 
@@ -58,17 +76,25 @@ fn part2(histories: &[History]) -> i32 {
         .sum()
 }
 
+const EXAMPLE: &str = r#"0 3 6 9 12 15
+1 3 6 10 15 21
+10 13 16 21 30 45"#;
+
+const EXAMPLES: &[Example] = &[Example {
+    name: "example",
+    input: EXAMPLE,
+    part1: Some("114"),
+    part2: Some("2"),
+    params: &[],
+}];
+
 #[cfg(test)]
 mod tests {
     use super::*;
     use crate::library::*;
 
     fn get_lines() -> Vec<History> {
-        let input = r#"0 3 6 9 12 15
-1 3 6 10 15 21
-10 13 16 21 30 45"#;
-
-        parse_input(&parse_lines(&input))
+        parse_input(&parse_lines(EXAMPLE)).unwrap()
     }
 
     #[test]
@@ -86,4 +112,17 @@ mod tests {
 
         Ok(())
     }
+
+    #[test]
+    fn test_parse_error() {
+        let error = parse_input(&parse_lines("0 3 6\n1 3 x6")).unwrap_err();
+        assert!(matches!(
+            error,
+            Error::Parse {
+                line: 2,
+                column: Some(5),
+                ..
+            }
+        ));
+    }
 }
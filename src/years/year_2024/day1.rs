@@ -1,28 +1,54 @@
-use crate::years::AdventDay;
+use crate::error::{Error, Result};
+use crate::library::parse::parse_number;
+use crate::library::validate::Check;
+use crate::years::{AdventDay, Context, Example, Solution, Solver};
 use std::collections::HashMap;
 
 pub struct Day1 {}
 
 impl AdventDay for Day1 {
-    fn solve(&self) {
-        let lines = self.get_input();
-        println!("Part1 solution: {}", part1(&lines));
-        println!("Part2 solution: {}", part2(&lines));
+    fn solve(&self, lines: &[String], ctx: &Context) -> Result<Solution> {
+        Solver::try_parse(ctx, || parse_lists(lines))?
+            .part1(|ids| part1(ids))
+            .part2(|ids| part2(ids))
     }
 
     fn get_input_path(&self) -> &str {
         "Inputs/2024/day1.txt"
     }
+
+    fn examples(&self) -> &'static [Example] {
+        EXAMPLES
+    }
+
+    fn input_checks(&self) -> &'static [Check] {
+        &[Check::Lines(r"\d+ +\d+")]
+    }
+}
+
+fn parse_lists(lines: &[String]) -> Result<Vec<(i32, i32)>> {
+    lines
+        .iter()
+        .enumerate()
+        .map(
+            |(line_idx, line)| match line.split_ascii_whitespace().collect::<Vec<&str>>()[..] {
+                [id1, id2] => Ok((
+                    parse_number(line, id1, line_idx)?,
+                    parse_number(line, id2, line_idx)?,
+                )),
+                _ => Err(Error::parse(line_idx, "expected '<id> <id>'")),
+            },
+        )
+        .collect()
 }
 
-fn part1(lines: &Vec<String>) -> i32 {
+fn part1(ids: &[(i32, i32)]) -> i32 {
     let mut vec1 = Vec::new();
     let mut vec2 = Vec::new();
 
-    for line in lines {
-        let mut ids = line.split_ascii_whitespace();
-        vec1.push(ids.next().unwrap().parse::<i32>().unwrap());
-        vec2.push(ids.next().unwrap().parse::<i32>().unwrap());
+    for &(id1, id2) in ids {
+        vec1.push(id1);
+        vec2.push(id2);
     }
 
     vec1.sort();
@@ -33,14 +59,11 @@ fn part1(lines: &Vec<String>) -> i32 {
         .fold(0, |acc, (id1, id2)| acc + (id1 - id2).abs())
 }
 
-fn part2(lines: &Vec<String>) -> i32 {
+fn part2(ids: &[(i32, i32)]) -> i32 {
     let mut numbers = Vec::new();
     let mut occurances = HashMap::new();
 
-    for line in lines {
-        let mut ids = line.split_ascii_whitespace();
-        let id1 = ids.next().unwrap().parse::<i32>().unwrap();
-        let id2 = ids.next().unwrap().parse::<i32>().unwrap();
+    for &(id1, id2) in ids {
         numbers.push(id1);
 
         if let Some(val) = occurances.get_mut(&id2) {
@@ -55,20 +78,42 @@ fn part2(lines: &Vec<String>) -> i32 {
     })
 }
 
-#[cfg(test)]
-mod tests {
-    use super::*;
-    use crate::library::*;
-
-    fn get_lines() -> Vec<String> {
-        let input = r#"3   4
+const EXAMPLE: &str = r#"3   4
 4   3
 2   5
 1   3
 3   9
 3   3"#;
 
-        parse_lines(&input)
+const EXAMPLES: &[Example] = &[Example {
+    name: "example",
+    input: EXAMPLE,
+    part1: Some("11"),
+    part2: Some("31"),
+    params: &[],
+}];
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::library::*;
+
+    fn get_lines() -> Vec<(i32, i32)> {
+        parse_lists(&parse_lines(EXAMPLE)).unwrap()
+    }
+
+    #[test]
+    fn test_parse_error() {
+        let error = parse_lists(&parse_lines("3   4\n4   3000000000")).unwrap_err();
+        assert!(matches!(
+            error,
+            Error::Parse {
+                line: 2,
+                column: Some(5),
+                ..
+            }
+        ));
+        assert!(parse_lists(&parse_lines("3")).is_err());
     }
 
     #[test]
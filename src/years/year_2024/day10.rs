@@ -1,37 +1,50 @@
 use super::{Map, Pos};
 use std::collections::{HashSet, VecDeque};
 
-use crate::years::AdventDay;
+use crate::error::{Error, Result};
+use crate::library::validate::Check;
+use crate::years::{AdventDay, Context, Example, Solution, Solver};
 
 pub struct Day10 {}
 
 impl AdventDay for Day10 {
-    fn solve(&self) {
-        let lines = self.get_input();
-        let (map, starting_positions) = parse_map(&lines);
-        println!("Part1 solution: {}", part1(&map, &starting_positions));
-        println!("Part2 solution: {}", part2(&map, &starting_positions));
+    fn solve(&self, lines: &[String], ctx: &Context) -> Result<Solution> {
+        Solver::try_parse(ctx, || parse_map(lines))?
+            .part1(|(map, starting_positions)| part1(map, starting_positions))
+            .part2(|(map, starting_positions)| part2(map, starting_positions))
     }
 
     fn get_input_path(&self) -> &str {
         "Inputs/2024/day10.txt"
     }
+
+    fn examples(&self) -> &'static [Example] {
+        EXAMPLES
+    }
+
+    fn input_checks(&self) -> &'static [Check] {
+        &[Check::Lines(r"[.0-9]+"), Check::SquareGrid]
+    }
 }
 
-fn parse_map(lines: &[String]) -> (Map<i32>, Vec<Pos>) {
+fn parse_map(lines: &[String]) -> Result<(Map<i32>, Vec<Pos>)> {
     let mut starting_positions = Vec::new();
-    let map = Map::new(lines, |val, pos| {
-        if val.is_ascii_digit() {
-            if val == '0' {
+    let map = Map::try_new(lines, |val, pos| match val.to_digit(10) {
+        Some(height) => {
+            if height == 0 {
                 starting_positions.push(pos.clone());
             }
-            val.to_string().parse::<i32>().unwrap()
-        } else {
-            0
+            Ok(height as i32)
         }
-    });
-
-    (map, starting_positions)
+        None if val == '.' => Ok(0),
+        None => Err(Error::parse_at(
+            pos.y as usize,
+            pos.x as usize,
+            format!("expected a height or '.', got '{val}'"),
+        )),
+    })?;
+
+    Ok((map, starting_positions))
 }
 
 fn part1(map: &Map<i32>, starting_positions: &[Pos]) -> usize {
@@ -72,13 +85,7 @@ fn part2(map: &Map<i32>, starting_positions: &[Pos]) -> usize {
     result
 }
 
-#[cfg(test)]
-mod tests {
-    use super::*;
-    use crate::library::*;
-
-    fn get_input() -> (Map<i32>, Vec<Pos>) {
-        let input = r#"89010123
+const EXAMPLE: &str = r#"89010123
 78121874
 87430965
 96549874
@@ -87,8 +94,98 @@ mod tests {
 01329801
 10456732"#;
 
-        let lines = parse_lines(&input);
-        parse_map(&lines)
+const EXAMPLE_1: &str = r#"...0...
+...1...
+...2...
+6543456
+7.....7
+8.....8
+9.....9"#;
+
+const EXAMPLE_2: &str = r#"..90..9
+...1.98
+...2..7
+6543456
+765.987
+876....
+987...."#;
+
+const EXAMPLE_3: &str = r#"10..9..
+2...8..
+3...7..
+4567654
+...8..3
+...9..2
+.....01"#;
+
+const EXAMPLE_4: &str = r#".....0.
+..4321.
+..5..2.
+..6543.
+..7..4.
+..8765.
+..9...."#;
+
+const EXAMPLE_5: &str = r#"012345
+123456
+234567
+345678
+4.6789
+56789."#;
+
+const EXAMPLES: &[Example] = &[
+    Example {
+        name: "example",
+        input: EXAMPLE,
+        part1: Some("36"),
+        part2: Some("81"),
+        params: &[],
+    },
+    Example {
+        name: "example1",
+        input: EXAMPLE_1,
+        part1: Some("2"),
+        part2: None,
+        params: &[],
+    },
+    Example {
+        name: "example2",
+        input: EXAMPLE_2,
+        part1: Some("4"),
+        part2: Some("13"),
+        params: &[],
+    },
+    Example {
+        name: "example3",
+        input: EXAMPLE_3,
+        part1: Some("3"),
+        part2: None,
+        params: &[],
+    },
+    Example {
+        name: "example4",
+        input: EXAMPLE_4,
+        part1: None,
+        part2: Some("3"),
+        params: &[],
+    },
+    Example {
+        name: "example5",
+        input: EXAMPLE_5,
+        part1: None,
+        part2: Some("227"),
+        params: &[],
+    },
+];
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::library::*;
+
+    fn get_input() -> (Map<i32>, Vec<Pos>) {
+        let lines = parse_lines(EXAMPLE);
+        parse_map(&lines).unwrap()
     }
 
     #[test]
@@ -100,16 +197,8 @@ mod tests {
     }
 
     fn get_input1() -> (Map<i32>, Vec<Pos>) {
-        let input = r#"...0...
-...1...
-...2...
-6543456
-7.....7
-8.....8
-9.....9"#;
-
-        let lines = parse_lines(&input);
-        parse_map(&lines)
+        let lines = parse_lines(EXAMPLE_1);
+        parse_map(&lines).unwrap()
     }
 
     #[test]
@@ -121,16 +210,8 @@ mod tests {
     }
 
     fn get_input2() -> (Map<i32>, Vec<Pos>) {
-        let input = r#"..90..9
-...1.98
-...2..7
-6543456
-765.987
-876....
-987...."#;
-
-        let lines = parse_lines(&input);
-        parse_map(&lines)
+        let lines = parse_lines(EXAMPLE_2);
+        parse_map(&lines).unwrap()
     }
 
     #[test]
@@ -142,16 +223,8 @@ mod tests {
     }
 
     fn get_input3() -> (Map<i32>, Vec<Pos>) {
-        let input = r#"10..9..
-2...8..
-3...7..
-4567654
-...8..3
-...9..2
-.....01"#;
-
-        let lines = parse_lines(&input);
-        parse_map(&lines)
+        let lines = parse_lines(EXAMPLE_3);
+        parse_map(&lines).unwrap()
     }
 
     #[test]
@@ -171,16 +244,8 @@ mod tests {
     }
 
     fn get_input4() -> (Map<i32>, Vec<Pos>) {
-        let input = r#".....0.
-..4321.
-..5..2.
-..6543.
-..7..4.
-..8765.
-..9...."#;
-
-        let lines = parse_lines(&input);
-        parse_map(&lines)
+        let lines = parse_lines(EXAMPLE_4);
+        parse_map(&lines).unwrap()
     }
 
     #[test]
@@ -192,16 +257,8 @@ mod tests {
     }
 
     fn get_input5() -> (Map<i32>, Vec<Pos>) {
-        let input = r#"..90..9
-...1.98
-...2..7
-6543456
-765.987
-876....
-987...."#;
-
-        let lines = parse_lines(&input);
-        parse_map(&lines)
+        let lines = parse_lines(EXAMPLE_2);
+        parse_map(&lines).unwrap()
     }
 
     #[test]
@@ -213,15 +270,8 @@ mod tests {
     }
 
     fn get_input6() -> (Map<i32>, Vec<Pos>) {
-        let input = r#"012345
-123456
-234567
-345678
-4.6789
-56789."#;
-
-        let lines = parse_lines(&input);
-        parse_map(&lines)
+        let lines = parse_lines(EXAMPLE_5);
+        parse_map(&lines).unwrap()
     }
 
     #[test]
@@ -231,4 +281,18 @@ mod tests {
 
         Ok(())
     }
+
+    #[test]
+    fn test_parse_error() {
+        let lines = parse_lines("0123\n98x4");
+        let error = parse_map(&lines).err().unwrap();
+        assert!(matches!(
+            error,
+            Error::Parse {
+                line: 2,
+                column: Some(3),
+                ..
+            }
+        ));
+    }
 }
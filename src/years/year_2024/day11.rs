@@ -1,30 +1,40 @@
-use super::parse_file;
 use std::collections::HashMap;
 
-use crate::years::AdventDay;
+use crate::error::Result;
+use crate::library::parse::parse_number;
+use crate::library::validate::Check;
+use crate::years::{AdventDay, Context, Example, Solution, Solver};
 
 pub struct Day11 {}
 
 impl AdventDay for Day11 {
-    fn solve(&self) {
-        if let Ok(line_string) = parse_file(self.get_input_path()) {
-            let stones = parse_line(&line_string);
-            println!("Part1 solution: {}", part1(&stones));
-            println!("Part2 solution: {}", part2(&stones));
-        } else {
-            println!("Could not parse file");
-        }
+    fn solve(&self, lines: &[String], ctx: &Context) -> Result<Solution> {
+        Solver::try_parse(ctx, || parse_stones(lines))?
+            .part1(|stones| part1(stones))
+            .part2(|stones| part2(stones))
     }
 
     fn get_input_path(&self) -> &str {
         "Inputs/2024/day11.txt"
     }
+
+    fn examples(&self) -> &'static [Example] {
+        EXAMPLES
+    }
+
+    fn input_checks(&self) -> &'static [Check] {
+        &[Check::Lines(r"\d+( \d+)*")]
+    }
 }
 
-fn parse_line(line: &str) -> Vec<usize> {
-    line.split_ascii_whitespace()
-        .map(|s| s.parse::<usize>().unwrap())
-        .collect()
+fn parse_stones(lines: &[String]) -> Result<Vec<usize>> {
+    let mut stones = Vec::new();
+    for (line_idx, line) in lines.iter().enumerate() {
+        for field in line.split_ascii_whitespace() {
+            stones.push(parse_number(line, field, line_idx)?);
+        }
+    }
+    Ok(stones)
 }
 
 fn part1(stones: &[usize]) -> usize {
@@ -94,13 +104,23 @@ fn part2(stones: &[usize]) -> usize {
         .fold(0, |acc, s| acc + recurse(*s, 75, &mut seen_values))
 }
 
+const EXAMPLE: &str = r#"125 17"#;
+
+const EXAMPLES: &[Example] = &[Example {
+    name: "example",
+    input: EXAMPLE,
+    part1: Some("55312"),
+    part2: None,
+    params: &[],
+}];
+
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::error::Error;
 
     fn get_lines() -> Vec<usize> {
-        let input = r#"125 17"#;
-        parse_line(&input)
+        parse_stones(&[EXAMPLE.to_string()]).unwrap()
     }
 
     #[test]
@@ -110,4 +130,18 @@ mod tests {
 
         Ok(())
     }
+
+    #[test]
+    fn test_parse_error() {
+        let lines = vec!["125 17".to_string(), "3 x7".to_string()];
+        let error = parse_stones(&lines).unwrap_err();
+        assert!(matches!(
+            error,
+            Error::Parse {
+                line: 2,
+                column: Some(3),
+                ..
+            }
+        ));
+    }
 }
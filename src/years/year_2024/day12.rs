@@ -1,21 +1,31 @@
 use super::{Map, Pos};
 use std::collections::{HashSet, VecDeque};
 
-use crate::years::AdventDay;
+use crate::error::Result;
+use crate::library::validate::Check;
+use crate::years::{AdventDay, Context, Example, Solution, Solver};
+use crate::{debug, trace};
 
 pub struct Day12 {}
 
 impl AdventDay for Day12 {
-    fn solve(&self) {
-        let lines = self.get_input();
-        let map = parse_map(&lines);
-        println!("Part1 solution: {}", part1(&map));
-        println!("Part2 solution: {}", part2(&map));
+    fn solve(&self, lines: &[String], ctx: &Context) -> Result<Solution> {
+        Solver::parse(ctx, || parse_map(lines))
+            .part1(part1)
+            .part2(part2)
     }
 
     fn get_input_path(&self) -> &str {
         "Inputs/2024/day12.txt"
     }
+
+    fn examples(&self) -> &'static [Example] {
+        EXAMPLES
+    }
+
+    fn input_checks(&self) -> &'static [Check] {
+        &[Check::Lines(r"[A-Z]+"), Check::SquareGrid]
+    }
 }
 
 fn parse_map(lines: &[String]) -> Map<u8> {
@@ -277,15 +287,15 @@ fn find_sides(region: &(u8, HashSet<Pos>), map: &Map<u8>) -> usize {
 
         if blah != corners {
             let diff = corners - blah;
-            println!(
+            trace!(
                 "{diff} new corners with : {position:?} => {clockwise_neighbors:?}, {clockwise_diagonal_neighbors:?}"
             );
         } else {
-            println!("{position:?} => {clockwise_neighbors:?}, {clockwise_diagonal_neighbors:?}");
+            trace!("{position:?} => {clockwise_neighbors:?}, {clockwise_diagonal_neighbors:?}");
         }
     }
 
-    println!("{} => {}", *plant_type as char, corners);
+    debug!("{} => {}", *plant_type as char, corners);
     corners
 }
 
@@ -300,34 +310,18 @@ fn part2(map: &Map<u8>) -> usize {
     result
 }
 
-#[cfg(test)]
-mod tests {
-    use super::*;
-    use crate::library::*;
-
-    fn get_input() -> Map<u8> {
-        let input = r#"AAAA
+const EXAMPLE: &str = r#"AAAA
 BBCD
 BBCC
 EEEC"#;
 
-        let lines = parse_lines(&input);
-        parse_map(&lines)
-    }
-
-    fn get_input1() -> Map<u8> {
-        let input = r#"OOOOO
+const EXAMPLE_1: &str = r#"OOOOO
 OXOXO
 OOOOO
 OXOXO
 OOOOO"#;
 
-        let lines = parse_lines(&input);
-        parse_map(&lines)
-    }
-
-    fn get_input2() -> Map<u8> {
-        let input = r#"RRRRIICCFF
+const EXAMPLE_2: &str = r#"RRRRIICCFF
 RRRRIICCCF
 VVRRRCCFFF
 VVRCCCJFFF
@@ -338,30 +332,84 @@ MIIIIIJJEE
 MIIISIJEEE
 MMMISSJEEE"#;
 
-        let lines = parse_lines(&input);
-        parse_map(&lines)
-    }
-
-    fn get_input3() -> Map<u8> {
-        let input = r#"EEEEE
+const EXAMPLE_3: &str = r#"EEEEE
 EXXXX
 EEEEE
 EXXXX
 EEEEE"#;
 
-        let lines = parse_lines(&input);
-        parse_map(&lines)
-    }
-
-    fn get_input4() -> Map<u8> {
-        let input = r#"AAAAAA
+const EXAMPLE_4: &str = r#"AAAAAA
 AAABBA
 AAABBA
 ABBAAA
 ABBAAA
 AAAAAA"#;
 
-        let lines = parse_lines(&input);
+const EXAMPLES: &[Example] = &[
+    Example {
+        name: "example",
+        input: EXAMPLE,
+        part1: Some("140"),
+        part2: Some("80"),
+        params: &[],
+    },
+    Example {
+        name: "example1",
+        input: EXAMPLE_1,
+        part1: Some("772"),
+        part2: Some("436"),
+        params: &[],
+    },
+    Example {
+        name: "example2",
+        input: EXAMPLE_2,
+        part1: Some("1930"),
+        part2: Some("1206"),
+        params: &[],
+    },
+    Example {
+        name: "example3",
+        input: EXAMPLE_3,
+        part1: None,
+        part2: Some("236"),
+        params: &[],
+    },
+    Example {
+        name: "example4",
+        input: EXAMPLE_4,
+        part1: None,
+        part2: Some("368"),
+        params: &[],
+    },
+];
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::library::*;
+
+    fn get_input() -> Map<u8> {
+        let lines = parse_lines(EXAMPLE);
+        parse_map(&lines)
+    }
+
+    fn get_input1() -> Map<u8> {
+        let lines = parse_lines(EXAMPLE_1);
+        parse_map(&lines)
+    }
+
+    fn get_input2() -> Map<u8> {
+        let lines = parse_lines(EXAMPLE_2);
+        parse_map(&lines)
+    }
+
+    fn get_input3() -> Map<u8> {
+        let lines = parse_lines(EXAMPLE_3);
+        parse_map(&lines)
+    }
+
+    fn get_input4() -> Map<u8> {
+        let lines = parse_lines(EXAMPLE_4);
         parse_map(&lines)
     }
 
@@ -1,20 +1,33 @@
 use super::{Equation, Pos, cramers_rule};
 
-use crate::years::AdventDay;
+use crate::error::{Error, Result};
+use crate::library::parse::signed_integers;
+use crate::library::text::InputText;
+use crate::library::validate::Check;
+use crate::years::{AdventDay, Context, Example, Solution, Solver};
 
 pub struct Day13 {}
 
 impl AdventDay for Day13 {
-    fn solve(&self) {
-        let lines = self.get_input();
-        let claw_machines = parse_claw_machines(&lines);
-        println!("Part1 solution: {}", part1(&claw_machines));
-        println!("Part2 solution: {}", part2(&claw_machines));
+    fn solve_input(&self, input: &InputText, ctx: &Context) -> Result<Solution> {
+        Solver::try_parse(ctx, || parse_claw_machines(input))?
+            .part1(|claw_machines| part1(claw_machines))
+            .part2(|claw_machines| part2(claw_machines))
     }
 
     fn get_input_path(&self) -> &str {
         "Inputs/2024/day13.txt"
     }
+
+    fn examples(&self) -> &'static [Example] {
+        EXAMPLES
+    }
+
+    fn input_checks(&self) -> &'static [Check] {
+        &[Check::Lines(
+            r"Button [AB]: X\+\d+, Y\+\d+|Prize: X=\d+, Y=\d+",
+        )]
+    }
 }
 
 #[derive(Clone, Debug, PartialEq, Eq)]
@@ -24,57 +37,32 @@ struct ClawMachine {
     prize: Pos,
 }
 
-fn parse_button(line: &str) -> Pos {
-    let (_, pos_str) = line.split_at(10);
-
-    let mut pos_slices = pos_str.split(',');
-
-    let x_str = pos_slices.next().unwrap().trim_start_matches("X+");
-    let x = x_str.parse::<i32>().unwrap();
-    let y_str = pos_slices.next().unwrap().trim_start_matches(" Y+");
-    let y = y_str.parse::<i32>().unwrap();
-
-    Pos { x, y }
-}
-
-fn parse_prize(line: &str) -> Pos {
-    let (_, pos_str) = line.split_at(7);
-
-    let mut pos_slices = pos_str.split(',');
-
-    let x = pos_slices
-        .next()
-        .unwrap()
-        .trim_start_matches("X=")
-        .parse::<i32>()
-        .unwrap();
-    let y = pos_slices
-        .next()
-        .unwrap()
-        .trim_start_matches(" Y=")
-        .parse::<i32>()
-        .unwrap();
-
-    Pos { x, y }
-}
-
-fn parse_claw_machines(lines: &[String]) -> Vec<ClawMachine> {
-    let mut claw_machines = Vec::new();
-    let mut idx = 0;
-    while idx < lines.len() {
-        let a = parse_button(&lines[idx]);
-        let b = parse_button(&lines[idx + 1]);
-        let prize = parse_prize(&lines[idx + 2]);
-
-        claw_machines.push(ClawMachine {
-            button_a: a,
-            button_b: b,
-            prize,
-        });
-        idx += 4;
+fn parse_xy(line: &str, line_idx: usize, prefix: &str) -> Result<Pos> {
+    let expected = || Error::parse(line_idx, format!("expected '{prefix} X<n>, Y<n>'"));
+    if !line.starts_with(prefix) {
+        return Err(expected());
     }
+    match signed_integers(line, line_idx)?[..] {
+        [x, y] => Ok(Pos { x, y }),
+        _ => Err(expected()),
+    }
+}
 
-    claw_machines
+fn parse_claw_machines(input: &InputText) -> Result<Vec<ClawMachine>> {
+    input
+        .sections()
+        .map(|section| match section.indexed().collect::<Vec<_>>()[..] {
+            [(a_idx, a), (b_idx, b), (prize_idx, prize)] => Ok(ClawMachine {
+                button_a: parse_xy(a, a_idx, "Button A:")?,
+                button_b: parse_xy(b, b_idx, "Button B:")?,
+                prize: parse_xy(prize, prize_idx, "Prize:")?,
+            }),
+            _ => Err(Error::parse(
+                section.start,
+                "expected the two buttons and the prize of a claw machine",
+            )),
+        })
+        .collect()
 }
 
 fn part1(claw_machines: &[ClawMachine]) -> usize {
@@ -129,13 +117,7 @@ fn part2(claw_machines: &[ClawMachine]) -> usize {
     result
 }
 
-#[cfg(test)]
-mod tests {
-    use super::*;
-    use crate::library::*;
-
-    fn get_lines() -> Vec<ClawMachine> {
-        let input = r#"Button A: X+94, Y+34
+const EXAMPLE: &str = r#"Button A: X+94, Y+34
 Button B: X+22, Y+67
 Prize: X=8400, Y=5400
 
@@ -150,8 +132,32 @@ Prize: X=7870, Y=6450
 Button A: X+69, Y+23
 Button B: X+27, Y+71
 Prize: X=18641, Y=10279"#;
-        let lines = parse_lines(&input);
-        parse_claw_machines(&lines)
+
+const EXAMPLES: &[Example] = &[Example {
+    name: "example",
+    input: EXAMPLE,
+    part1: Some("480"),
+    part2: Some("875318608908"),
+    params: &[],
+}];
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn get_lines() -> Vec<ClawMachine> {
+        parse_claw_machines(&InputText::from(EXAMPLE)).unwrap()
+    }
+
+    #[test]
+    fn test_parse_error() {
+        let input = InputText::from("Button A: X+94, Y+34\nButton B: X+22\nPrize: X=8400, Y=5400");
+        let error = parse_claw_machines(&input).unwrap_err();
+        assert!(matches!(error, Error::Parse { line: 2, .. }), "{error}");
+
+        let input = InputText::from("Button A: X+94, Y+34\nButton B: X+22, Y+67");
+        let error = parse_claw_machines(&input).unwrap_err();
+        assert!(matches!(error, Error::Parse { line: 1, .. }), "{error}");
     }
 
     #[test]
@@ -1,21 +1,50 @@
 use super::{Dir, Pos};
 
-use crate::years::AdventDay;
+use crate::debug;
+use crate::error::{Error, Result};
+use crate::library::parse::signed_integers;
+use crate::library::text::InputText;
+use crate::library::validate::Check;
+use crate::log::render_grid;
+use crate::years::{AdventDay, Context, Example, Param, Solution, Solver};
 
 pub struct Day14 {}
 
 impl AdventDay for Day14 {
-    fn solve(&self) {
-        let lines = self.get_input();
-        let robots = parse_map(&lines);
-        let map_size = Pos { x: 101, y: 103 };
-        println!("Part1 solution: {}", part1(&map_size, &robots));
-        println!("Part2 solution: {}", part2(&map_size, &robots));
+    fn solve_input(&self, input: &InputText, ctx: &Context) -> Result<Solution> {
+        let map_size = Pos {
+            x: ctx.param("width")?,
+            y: ctx.param("height")?,
+        };
+        Solver::try_parse(ctx, || parse_map(input))?
+            .part1(|robots| part1(&map_size, robots))
+            .part2(|robots| part2(&map_size, robots, ctx))
     }
 
     fn get_input_path(&self) -> &str {
         "Inputs/2024/day14.txt"
     }
+
+    fn examples(&self) -> &'static [Example] {
+        EXAMPLES
+    }
+
+    fn input_checks(&self) -> &'static [Check] {
+        &[Check::Lines(r"p=-?\d+,-?\d+ v=-?\d+,-?\d+")]
+    }
+
+    fn params(&self) -> &'static [Param] {
+        &[
+            Param {
+                name: "width",
+                default: "101",
+            },
+            Param {
+                name: "height",
+                default: "103",
+            },
+        ]
+    }
 }
 
 #[derive(Debug, Clone)]
@@ -24,34 +53,22 @@ struct Robot {
     dir: Dir,
 }
 
-fn parse_vec2(str: &str) -> (i32, i32) {
-    let mut split = str.split(',');
-
-    let x = split.next().unwrap().parse::<i32>().unwrap();
-    let y = split.next().unwrap().parse::<i32>().unwrap();
-
-    (x, y)
-}
-
-fn parse_map(lines: &[String]) -> Vec<Robot> {
-    let mut robots = Vec::new();
-
-    for line in lines.iter() {
-        let mut slices = line.split_ascii_whitespace();
-
-        let pos_str = slices.next().unwrap().trim_start_matches("p=");
-        let (pos_x, pos_y) = parse_vec2(pos_str);
-
-        let dir_str = slices.next().unwrap().trim_start_matches("v=");
-        let (dir_x, dir_y) = parse_vec2(dir_str);
-
-        robots.push(Robot {
+fn parse_robot(line: &str, line_idx: usize) -> Result<Robot> {
+    match signed_integers(line, line_idx)?[..] {
+        [pos_x, pos_y, dir_x, dir_y] => Ok(Robot {
             pos: Pos { x: pos_x, y: pos_y },
             dir: Dir::new(dir_x, dir_y),
-        })
+        }),
+        _ => Err(Error::parse(line_idx, "expected 'p=<x>,<y> v=<dx>,<dy>'")),
     }
+}
 
-    robots
+fn parse_map(input: &InputText) -> Result<Vec<Robot>> {
+    input
+        .lines()
+        .enumerate()
+        .map(|(line_idx, line)| parse_robot(line, line_idx))
+        .collect()
 }
 
 fn is_upper_range(x: i32, divider: i32) -> Option<bool> {
@@ -137,13 +154,14 @@ fn found_multiple_robots_in_a_row(robots: &[Robot]) -> bool {
     robots_in_row >= required_robots_in_a_row
 }
 
-fn part2(map_size: &Pos, robots: &[Robot]) -> usize {
+fn part2(map_size: &Pos, robots: &[Robot], ctx: &Context) -> usize {
     let mut curr_position_robots = robots.to_vec();
 
     let mut steps = 0;
     let steps_for_repeating_pattern = map_size.x as usize * map_size.y as usize;
     while !found_multiple_robots_in_a_row(&curr_position_robots)
         && steps < steps_for_repeating_pattern
+        && !ctx.cancelled()
     {
         for robot in curr_position_robots.iter_mut() {
             let new_pos = &robot.pos + &robot.dir;
@@ -160,29 +178,20 @@ fn part2(map_size: &Pos, robots: &[Robot]) -> usize {
         steps += 1;
     }
 
-    for y in 0..map_size.y {
-        for x in 0..map_size.x {
-            if curr_position_robots
-                .iter()
-                .any(|robot| robot.pos == Pos { x, y })
-            {
-                print!("X");
+    debug!(
+        "robots after {steps} seconds:\n{}",
+        render_grid(map_size.x, map_size.y, |pos| {
+            if curr_position_robots.iter().any(|robot| robot.pos == pos) {
+                'X'
             } else {
-                print!(".");
+                '.'
             }
-        }
-        println!();
-    }
+        })
+    );
     steps
 }
 
-#[cfg(test)]
-mod tests {
-    use super::*;
-    use crate::library::*;
-
-    fn get_robots() -> (Pos, Vec<Robot>) {
-        let input = r#"p=0,4 v=3,-3
+const EXAMPLE: &str = r#"p=0,4 v=3,-3
 p=6,3 v=-1,-3
 p=10,3 v=-1,2
 p=2,0 v=2,-1
@@ -195,8 +204,28 @@ p=7,3 v=-1,2
 p=2,4 v=2,-3
 p=9,5 v=-3,-3"#;
 
-        let lines = parse_lines(&input);
-        (Pos { x: 11, y: 7 }, parse_map(&lines))
+const EXAMPLES: &[Example] = &[Example {
+    name: "example",
+    input: EXAMPLE,
+    part1: Some("12"),
+    part2: None,
+    params: &[("width", "11"), ("height", "7")],
+}];
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn get_robots() -> (Pos, Vec<Robot>) {
+        let input = InputText::from(EXAMPLE);
+        (Pos { x: 11, y: 7 }, parse_map(&input).unwrap())
+    }
+
+    #[test]
+    fn test_parse_error() {
+        let input = InputText::from("p=0,4 v=3,-3\np=6,3 v=-1");
+        let error = parse_map(&input).unwrap_err();
+        assert!(matches!(error, Error::Parse { line: 2, .. }), "{error}");
     }
 
     #[test]
@@ -279,7 +308,7 @@ p=9,5 v=-3,-3"#;
                 dir: Dir::new(0, 0),
             },
         ];
-        assert_eq!(found_multiple_robots_in_a_row(&robots), true);
+        assert!(found_multiple_robots_in_a_row(&robots));
 
         Ok(())
     }
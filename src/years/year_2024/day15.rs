@@ -1,27 +1,38 @@
 use super::{DOWN, Dir, LEFT, Pos, RIGHT, UP};
 use std::collections::HashSet;
 
-use crate::years::AdventDay;
+use crate::error::{Error, Result};
+use crate::library::parse::split_sections;
+use crate::library::text::InputText;
+use crate::library::validate::Check;
+use crate::log::render_grid;
+use crate::years::{AdventDay, Context, Example, Solution, Solver};
+use crate::{debug, trace};
 
 pub struct Day15 {}
 
 impl AdventDay for Day15 {
-    fn solve(&self) {
-        let lines = self.get_input();
-        let (robot_pos, walls, boxes, instructions) = parse_map(&lines);
-        println!(
-            "Part1 solution: {}",
-            part1(&robot_pos, &walls, &boxes, &instructions)
-        );
-        println!(
-            "Part2 solution: {}",
-            part2(&robot_pos, &walls, &boxes, &instructions)
-        );
+    fn solve_input(&self, input: &InputText, ctx: &Context) -> Result<Solution> {
+        Solver::try_parse(ctx, || parse_map(input))?
+            .part1(|(robot_pos, walls, boxes, instructions)| {
+                part1(robot_pos, walls, boxes, instructions)
+            })
+            .part2(|(robot_pos, walls, boxes, instructions)| {
+                part2(robot_pos, walls, boxes, instructions)
+            })
     }
 
     fn get_input_path(&self) -> &str {
         "Inputs/2024/day15.txt"
     }
+
+    fn examples(&self) -> &'static [Example] {
+        EXAMPLES
+    }
+
+    fn input_checks(&self) -> &'static [Check] {
+        &[Check::Chars("#.O@<>^v"), Check::Grid]
+    }
 }
 
 type Wall = Pos;
@@ -29,51 +40,57 @@ type Walls = HashSet<Wall>;
 type Box = (usize, Pos);
 type Boxes = Vec<Box>;
 
-fn parse_map(lines: &[String]) -> (Pos, Walls, Boxes, Vec<&'static Dir>) {
+type Input = (Pos, Walls, Boxes, Vec<&'static Dir>);
+
+fn parse_map(input: &InputText) -> Result<Input> {
+    let [map, moves] = split_sections(input)?;
     let mut boxes = Boxes::new();
     let mut walls = Walls::new();
     let mut instructions = Vec::new();
     let mut robot_pos = Pos { x: 0, y: 0 };
     let mut idx = 0;
 
-    let mut parse_instructuins = false;
-    for (row, line) in lines.iter().enumerate() {
-        if line.is_empty() {
-            parse_instructuins = true;
-            continue;
+    for (row, line) in map.indexed() {
+        for (col, char) in line.chars().enumerate() {
+            let x = col as i32;
+            let y = row as i32;
+            let pos = Pos { x, y };
+            match char {
+                '#' => {
+                    walls.insert(pos);
+                }
+                'O' => {
+                    boxes.push((idx, pos));
+                    idx += 1;
+                }
+                '@' => robot_pos = pos,
+                '.' => {}
+                c => {
+                    return Err(Error::parse_at(
+                        row,
+                        col,
+                        format!("unexpected map tile '{c}'"),
+                    ));
+                }
+            }
         }
+    }
 
-        if !parse_instructuins {
-            for (col, char) in line.chars().enumerate() {
-                let x = col as i32;
-                let y = row as i32;
-                let pos = Pos { x, y };
-                match char {
-                    '#' => {
-                        walls.insert(pos);
-                    }
-                    'O' => {
-                        boxes.push((idx, pos));
-                        idx += 1;
-                    }
-                    '@' => robot_pos = pos,
-                    _ => {}
+    for (row, line) in moves.indexed() {
+        for (col, char) in line.chars().enumerate() {
+            instructions.push(match char {
+                '^' => UP,
+                'v' => DOWN,
+                '<' => LEFT,
+                '>' => RIGHT,
+                c => {
+                    return Err(Error::parse_at(row, col, format!("unexpected move '{c}'")));
                 }
-            }
-        } else {
-            for char in line.chars() {
-                instructions.push(match char {
-                    '^' => UP,
-                    'v' => DOWN,
-                    '<' => LEFT,
-                    '>' => RIGHT,
-                    c => panic!("Gon unsupported instruction char {c} on line {row}"),
-                })
-            }
+            })
         }
     }
 
-    (robot_pos, walls, boxes, instructions)
+    Ok((robot_pos, walls, boxes, instructions))
 }
 
 fn part1(start_robot_pos: &Pos, walls: &Walls, boxes: &Boxes, instructions: &[&Dir]) -> usize {
@@ -87,7 +104,15 @@ fn part1(start_robot_pos: &Pos, walls: &Walls, boxes: &Boxes, instructions: &[&D
             walls,
             instruction,
         );
+        trace!(
+            "after {instruction:?}:\n{}",
+            render_map(&curr_robot_pos, walls, &curr_box_positions, false)
+        );
     }
+    debug!(
+        "final map:\n{}",
+        render_map(&curr_robot_pos, walls, &curr_box_positions, false)
+    );
 
     curr_box_positions.iter().fold(0, |acc, (_, pos)| {
         acc + pos.x as usize + 100 * pos.y as usize
@@ -138,51 +163,28 @@ fn try_move_in_dir(robot_pos: &mut Pos, boxes: &mut Boxes, walls: &Walls, dir: &
     }
 }
 
-fn _print_map(robot_pos: &Pos, walls: &Walls, boxes: &Boxes, large: bool) {
-    let mut max_x = 0;
-    let mut max_y = 0;
-    for pos in walls.iter() {
-        max_x = i32::max(max_x, pos.x);
-        max_y = i32::max(max_y, pos.y);
-    }
+fn render_map(robot_pos: &Pos, walls: &Walls, boxes: &Boxes, large: bool) -> String {
+    let max_x = walls.iter().map(|pos| pos.x).max().unwrap_or(0);
+    let max_y = walls.iter().map(|pos| pos.y).max().unwrap_or(0);
+    let box_at = |pos: &Pos| boxes.iter().any(|(_, box_pos)| box_pos == pos);
 
-    let mut box_count = 0;
-    for y in 0..=max_y {
-        let mut x = 0;
-        loop {
-            let curr_pos = Pos { x, y };
-
-            if curr_pos == *robot_pos {
-                print!("@");
-            } else if walls.contains(&curr_pos) {
-                print!("#");
-            } else if let Some((idx, _)) = boxes.iter().find(|(_, pos)| *pos == curr_pos) {
-                if large {
-                    print!("{idx}");
-                    if !boxes.iter().any(|(_, pos)| {
-                        *pos == Pos {
-                            x: curr_pos.x + 1,
-                            y: curr_pos.y,
-                        }
-                    }) {
-                        print!("]");
-                        x += 1;
-                    }
-                } else {
-                    print!("O")
-                }
-                box_count += 1;
-            } else {
-                print!(".");
-            }
-            x += 1;
-            if x > max_x {
-                break;
-            }
+    render_grid(max_x + 1, max_y + 1, |pos| {
+        let left = Pos {
+            x: pos.x - 1,
+            y: pos.y,
+        };
+        if pos == *robot_pos {
+            '@'
+        } else if walls.contains(&pos) {
+            '#'
+        } else if box_at(&pos) {
+            if large { '[' } else { 'O' }
+        } else if large && box_at(&left) {
+            ']'
+        } else {
+            '.'
         }
-        println!();
-    }
-    println!("Boxes printed: {box_count}");
+    })
 }
 
 fn grow_map(start_pos: &Pos, boxes: &Boxes, walls: &Walls) -> (Pos, Boxes, Walls) {
@@ -331,20 +333,22 @@ fn part2(start_robot_pos: &Pos, walls: &Walls, boxes: &Boxes, instructions: &[&D
 
     for instruction in instructions.iter() {
         try_recursive_move_in_dir(&mut curr_robot_pos, &mut boxes, &walls, instruction);
+        trace!(
+            "after {instruction:?}:\n{}",
+            render_map(&curr_robot_pos, &walls, &boxes, true)
+        );
     }
+    debug!(
+        "final map:\n{}",
+        render_map(&curr_robot_pos, &walls, &boxes, true)
+    );
 
     boxes.iter().fold(0, |acc, (_, pos)| {
         acc + pos.x as usize + 100 * pos.y as usize
     })
 }
 
-#[cfg(test)]
-mod tests {
-    use super::*;
-    use crate::library::*;
-
-    fn get_smallest_input() -> (Pos, Walls, Boxes, Vec<&'static Dir>) {
-        let input = r#"#######
+const EXAMPLE_SMALLEST: &str = r#"#######
 #...#.#
 #.....#
 #..OO@#
@@ -353,12 +357,8 @@ mod tests {
 #######
 
 <vv<<^^<<^^"#;
-        let lines = parse_lines(&input);
-        parse_map(&lines)
-    }
 
-    fn get_small_input() -> (Pos, Walls, Boxes, Vec<&'static Dir>) {
-        let input = r#"########
+const EXAMPLE_SMALL: &str = r#"########
 #..O.O.#
 ##@.O..#
 #...O..#
@@ -369,12 +369,7 @@ mod tests {
 
 <^^>>>vv<v>>v<<"#;
 
-        let lines = parse_lines(&input);
-        parse_map(&lines)
-    }
-
-    fn get_large_input() -> (Pos, Walls, Boxes, Vec<&'static Dir>) {
-        let input = r#"##########
+const EXAMPLE_LARGE: &str = r#"##########
 #..O..O.O#
 #......O.#
 #.OO..O.O#
@@ -396,8 +391,54 @@ vvv<<^>^v^^><<>>><>^<<><^vv^^<>vvv<>><^^v>^>vv<>v<<<<v<^v>^<^^>>>^<v<v
 ^^>vv<^v^v<vv>^<><v<^v>^^^>>>^^vvv^>vvv<>>>^<^>>>>>^<<^v>^vvv<>^<><<v>
 v^^>>><<^^<>>^v^<v^vv<>v^<<>^<^v^v><^<<<><<^<v><v<>vv>>v><v^<vv<>v^<<^"#;
 
-        let lines = parse_lines(&input);
-        parse_map(&lines)
+const EXAMPLES: &[Example] = &[
+    Example {
+        name: "smallest",
+        input: EXAMPLE_SMALLEST,
+        part1: None,
+        part2: Some("618"),
+        params: &[],
+    },
+    Example {
+        name: "small",
+        input: EXAMPLE_SMALL,
+        part1: Some("2028"),
+        part2: None,
+        params: &[],
+    },
+    Example {
+        name: "large",
+        input: EXAMPLE_LARGE,
+        part1: Some("10092"),
+        part2: Some("9021"),
+        params: &[],
+    },
+];
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn get_smallest_input() -> Input {
+        let input = InputText::from(EXAMPLE_SMALLEST);
+        parse_map(&input).unwrap()
+    }
+
+    fn get_small_input() -> Input {
+        let input = InputText::from(EXAMPLE_SMALL);
+        parse_map(&input).unwrap()
+    }
+
+    fn get_large_input() -> Input {
+        let input = InputText::from(EXAMPLE_LARGE);
+        parse_map(&input).unwrap()
+    }
+
+    #[test]
+    fn test_parse_error() {
+        let input = InputText::from("#####\n#@.O#\n#####\n\n<^>\n^x");
+        let error = parse_map(&input).unwrap_err();
+        assert_eq!(error.to_string(), "line 6, column 2: unexpected move 'x'");
     }
 
     #[test]
@@ -416,6 +457,30 @@ v^^>>><<^^<>>^v^<v^vv<>v^<<>^<^v^v><^<<<><<^<v><v<>vv>>v><v^<vv<>v^<<^"#;
         Ok(())
     }
 
+    #[test]
+    fn test_render_map() {
+        let (robot_pos, walls, boxes, _) = get_large_input();
+        assert_eq!(
+            render_map(&robot_pos, &walls, &boxes, false),
+            EXAMPLE_LARGE.split("\n\n").next().unwrap()
+        );
+
+        let (robot_pos, boxes, walls) = grow_map(&robot_pos, &boxes, &walls);
+        assert_eq!(
+            render_map(&robot_pos, &walls, &boxes, true),
+            r#"####################
+##....[]....[]..[]##
+##............[]..##
+##..[][]....[]..[]##
+##....[]@.....[]..##
+##[]##....[]......##
+##[]....[]....[]..##
+##..[][]..[]..[][]##
+##........[]......##
+####################"#
+        );
+    }
+
     #[test]
     fn test_part2_smallest() -> Result<(), String> {
         let (robot_pos, walls, boxes, instructions) = get_smallest_input();
@@ -448,8 +513,8 @@ v^^>>><<^^<>>^v^<v^vv<>v^<<>^<^v^v><^<<<><<^<v><v<>vv>>v><v^<vv<>v^<<^"#;
 
 <v<^^^^"#;
 
-        let lines = parse_lines(&input);
-        let (robot_pos, walls, boxes, instructions) = parse_map(&lines);
+        let input = InputText::from(input);
+        let (robot_pos, walls, boxes, instructions) = parse_map(&input).unwrap();
 
         assert_eq!(part2(&robot_pos, &walls, &boxes, &instructions), 1237);
 
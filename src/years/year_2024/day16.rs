@@ -4,21 +4,30 @@ use std::{
     collections::{BinaryHeap, HashMap, HashSet, VecDeque},
 };
 
-use crate::years::AdventDay;
+use crate::error::{Error, Result};
+use crate::library::validate::Check;
+use crate::years::{AdventDay, Context, Example, Solution, Solver};
 
 pub struct Day16 {}
 
 impl AdventDay for Day16 {
-    fn solve(&self) {
-        let lines = self.get_input();
-        let (start_pos, end_pos, map) = parse_map(&lines);
-        println!("Part1 solution: {}", part1(&start_pos, &end_pos, &map));
-        println!("Part2 solution: {}", part2(&start_pos, &end_pos, &map));
+    fn solve(&self, lines: &[String], ctx: &Context) -> Result<Solution> {
+        Solver::try_parse(ctx, || parse_map(lines))?
+            .part1(|(start_pos, end_pos, map)| part1(start_pos, end_pos, map))
+            .part2(|(start_pos, end_pos, map)| part2(start_pos, end_pos, map))
     }
 
     fn get_input_path(&self) -> &str {
         "Inputs/2024/day16.txt"
     }
+
+    fn examples(&self) -> &'static [Example] {
+        EXAMPLES
+    }
+
+    fn input_checks(&self) -> &'static [Check] {
+        &[Check::Chars("#.SE"), Check::SquareGrid]
+    }
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, PartialOrd)]
@@ -27,23 +36,28 @@ enum Space {
     Empty,
 }
 
-fn parse_map(lines: &[String]) -> (Pos, Pos, Map<Space>) {
+fn parse_map(lines: &[String]) -> Result<(Pos, Pos, Map<Space>)> {
     let mut start_pos = Pos { x: 0, y: 0 };
     let mut end_pos = Pos { x: 0, y: 0 };
-    let map = Map::new(lines, |char, pos| match char {
-        '#' => Space::Wall,
+    let map = Map::try_new(lines, |char, pos| match char {
+        '#' => Ok(Space::Wall),
         'S' => {
             start_pos = pos.clone();
-            Space::Empty
+            Ok(Space::Empty)
         }
         'E' => {
             end_pos = pos.clone();
-            Space::Empty
+            Ok(Space::Empty)
         }
-        _ => Space::Empty,
-    });
-
-    (start_pos, end_pos, map)
+        '.' => Ok(Space::Empty),
+        _ => Err(Error::parse_at(
+            pos.y as usize,
+            pos.x as usize,
+            format!("expected '.', '#', 'S' or 'E', got '{char}'"),
+        )),
+    })?;
+
+    Ok((start_pos, end_pos, map))
 }
 
 fn rotate(dir: &Dir) -> (&Dir, &Dir) {
@@ -233,13 +247,7 @@ fn find_optimal_paths(
     optimal_paths
 }
 
-#[cfg(test)]
-mod tests {
-    use super::*;
-    use crate::library::*;
-
-    fn get_small_input() -> (Pos, Pos, Map<Space>) {
-        let input = r#"###############
+const EXAMPLE_SMALL: &str = r#"###############
 #.......#....E#
 #.#.###.#.###.#
 #.....#.#...#.#
@@ -255,12 +263,7 @@ mod tests {
 #S..#.....#...#
 ###############"#;
 
-        let lines = parse_lines(&input);
-        parse_map(&lines)
-    }
-
-    fn get_large_input() -> (Pos, Pos, Map<Space>) {
-        let input = r#"#################
+const EXAMPLE_LARGE: &str = r#"#################
 #...#...#...#..E#
 #.#.#.#.#.#.#.#.#
 #.#.#.#...#...#.#
@@ -278,8 +281,36 @@ mod tests {
 #S#.............#
 #################"#;
 
-        let lines = parse_lines(&input);
-        parse_map(&lines)
+const EXAMPLES: &[Example] = &[
+    Example {
+        name: "small",
+        input: EXAMPLE_SMALL,
+        part1: Some("7036"),
+        part2: Some("45"),
+        params: &[],
+    },
+    Example {
+        name: "large",
+        input: EXAMPLE_LARGE,
+        part1: Some("11048"),
+        part2: Some("64"),
+        params: &[],
+    },
+];
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::library::*;
+
+    fn get_small_input() -> (Pos, Pos, Map<Space>) {
+        let lines = parse_lines(EXAMPLE_SMALL);
+        parse_map(&lines).unwrap()
+    }
+
+    fn get_large_input() -> (Pos, Pos, Map<Space>) {
+        let lines = parse_lines(EXAMPLE_LARGE);
+        parse_map(&lines).unwrap()
     }
 
     #[test]
@@ -313,4 +344,18 @@ mod tests {
 
         Ok(())
     }
+
+    #[test]
+    fn test_parse_error() {
+        let lines = parse_lines("#S.#\n#?E#");
+        let error = parse_map(&lines).err().unwrap();
+        assert!(matches!(
+            error,
+            Error::Parse {
+                line: 2,
+                column: Some(2),
+                ..
+            }
+        ));
+    }
 }
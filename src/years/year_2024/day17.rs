@@ -1,22 +1,27 @@
-use crate::years::AdventDay;
+use crate::error::{Error, Result, offset_in};
+use crate::library::validate::Check;
+use crate::years::{AdventDay, Context, Example, Solution, Solver};
 
 pub struct Day17 {}
 
 impl AdventDay for Day17 {
-    fn solve(&self) {
-        let lines = self.get_input();
-        let (registers, program) = parse_program(&lines);
-
-        let (_, part1_solution) = part1(&registers, &program);
-        let (registers, _) = part2(&program);
-
-        println!("Part1 solution: {part1_solution}",);
-        println!("Part2 solution: {}", registers.a);
+    fn solve(&self, lines: &[String], ctx: &Context) -> Result<Solution> {
+        Solver::try_parse(ctx, || parse_program(lines))?
+            .part1(|(registers, program)| part1(registers, program).1)
+            .part2(|(_, program)| part2(program).0.a)
     }
 
     fn get_input_path(&self) -> &str {
         "Inputs/2024/day17.txt"
     }
+
+    fn examples(&self) -> &'static [Example] {
+        EXAMPLES
+    }
+
+    fn input_checks(&self) -> &'static [Check] {
+        &[Check::Lines(r"Register [ABC]: \d+|Program: \d(,\d)*")]
+    }
 }
 
 #[derive(Debug, Clone, PartialEq)]
@@ -28,27 +33,44 @@ struct Registers {
 
 type Program = Vec<u64>;
 
-fn parse_program(lines: &[String]) -> (Registers, Program) {
-    let mut registers = Registers { a: 0, b: 0, c: 0 };
-    let mut program = Vec::new();
-
-    for line in lines {
-        if line.starts_with("Register A: ") {
-            registers.a = line.split(": ").nth(1).unwrap().parse().unwrap();
-        } else if line.starts_with("Register B: ") {
-            registers.b = line.split(": ").nth(1).unwrap().parse().unwrap();
-        } else if line.starts_with("Register C: ") {
-            registers.c = line.split(": ").nth(1).unwrap().parse().unwrap();
-        } else if line.starts_with("Program: ") {
-            let program_str = line.split(": ").nth(1).unwrap();
-            program = program_str
-                .split(",")
-                .map(|s| s.parse::<u64>().unwrap())
-                .collect::<Vec<u64>>();
-        }
-    }
+fn parse_register(lines: &[String], idx: usize, name: char) -> Result<u64> {
+    let expected = || Error::parse(idx, format!("expected 'Register {name}: <n>'"));
+    lines
+        .get(idx)
+        .and_then(|line| line.strip_prefix(&format!("Register {name}: ")))
+        .ok_or_else(expected)?
+        .parse()
+        .map_err(|_| expected())
+}
 
-    (registers, program)
+fn parse_program(lines: &[String]) -> Result<(Registers, Program)> {
+    let registers = Registers {
+        a: parse_register(lines, 0, 'A')?,
+        b: parse_register(lines, 1, 'B')?,
+        c: parse_register(lines, 2, 'C')?,
+    };
+
+    let program_idx = 4;
+    let line = lines
+        .get(program_idx)
+        .ok_or_else(|| Error::parse(program_idx, "expected 'Program: <n>,<n>,...'"))?;
+    let program_str = line
+        .strip_prefix("Program: ")
+        .ok_or_else(|| Error::parse(program_idx, "expected 'Program: <n>,<n>,...'"))?;
+    let program = program_str
+        .split(",")
+        .map(|s| {
+            s.parse::<u64>().map_err(|_| {
+                Error::parse_at(
+                    program_idx,
+                    offset_in(line, s),
+                    format!("expected a number, got '{s}'"),
+                )
+            })
+        })
+        .collect::<Result<Program>>()?;
+
+    Ok((registers, program))
 }
 
 fn get_literal_operand_value(operand: u64) -> u64 {
@@ -188,20 +210,43 @@ fn part2(program: &Program) -> (Registers, String) {
     (Registers { a: 0, b: 0, c: 0 }, "".to_string())
 }
 
+const EXAMPLE: &str = r#"Register A: 729
+Register B: 0
+Register C: 0
+
+Program: 0,1,5,4,3,0"#;
+
+const EXAMPLE_QUINE: &str = r#"Register A: 2024
+Register B: 0
+Register C: 0
+
+Program: 0,3,5,4,3,0"#;
+
+const EXAMPLES: &[Example] = &[
+    Example {
+        name: "example",
+        input: EXAMPLE,
+        part1: Some("4,6,3,5,6,3,5,2,1,0"),
+        part2: None,
+        params: &[],
+    },
+    Example {
+        name: "quine",
+        input: EXAMPLE_QUINE,
+        part1: None,
+        part2: Some("117440"),
+        params: &[],
+    },
+];
+
 #[cfg(test)]
 mod tests {
     use super::*;
     use crate::library::*;
 
     fn get_example() -> (Registers, Program) {
-        let input = r#"Register A: 729
-Register B: 0
-Register C: 0
-
-Program: 0,1,5,4,3,0"#;
-
-        let lines = parse_lines(&input);
-        parse_program(&lines)
+        let lines = parse_lines(EXAMPLE);
+        parse_program(&lines).unwrap()
     }
 
     fn get_example1() -> (Registers, Program) {
@@ -211,8 +256,8 @@ Register C: 9
 
 Program: 2,6"#;
 
-        let lines = parse_lines(&input);
-        parse_program(&lines)
+        let lines = parse_lines(input);
+        parse_program(&lines).unwrap()
     }
 
     fn get_example2() -> (Registers, Program) {
@@ -222,8 +267,8 @@ Register C: 0
 
 Program: 5,0,5,1,5,4"#;
 
-        let lines = parse_lines(&input);
-        parse_program(&lines)
+        let lines = parse_lines(input);
+        parse_program(&lines).unwrap()
     }
 
     fn get_example3() -> (Registers, Program) {
@@ -233,8 +278,8 @@ Register C: 0
 
 Program: 0,1,5,4,3,0"#;
 
-        let lines = parse_lines(&input);
-        parse_program(&lines)
+        let lines = parse_lines(input);
+        parse_program(&lines).unwrap()
     }
 
     fn get_example4() -> (Registers, Program) {
@@ -244,8 +289,8 @@ Register C: 0
 
 Program: 1,7"#;
 
-        let lines = parse_lines(&input);
-        parse_program(&lines)
+        let lines = parse_lines(input);
+        parse_program(&lines).unwrap()
     }
 
     fn get_example5() -> (Registers, Program) {
@@ -255,19 +300,27 @@ Register C: 43690
 
 Program: 4,0"#;
 
-        let lines = parse_lines(&input);
-        parse_program(&lines)
+        let lines = parse_lines(input);
+        parse_program(&lines).unwrap()
     }
 
     fn get_example6() -> (Registers, Program) {
-        let input = r#"Register A: 2024
-Register B: 0
-Register C: 0
-
-Program: 0,3,5,4,3,0"#;
+        let lines = parse_lines(EXAMPLE_QUINE);
+        parse_program(&lines).unwrap()
+    }
 
-        let lines = parse_lines(&input);
-        parse_program(&lines)
+    #[test]
+    fn test_parse_error() {
+        let lines = parse_lines("Register A: x\nRegister B: 0\nRegister C: 0\n\nProgram: 0,3");
+        let error = parse_program(&lines).unwrap_err();
+        assert_eq!(error.to_string(), "line 1: expected 'Register A: <n>'");
+
+        let lines = parse_lines("Register A: 1\nRegister B: 0\nRegister C: 0\n\nProgram: 0,x");
+        let error = parse_program(&lines).unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "line 5, column 12: expected a number, got 'x'"
+        );
     }
 
     #[test]
@@ -1,37 +1,67 @@
 use super::{DIRECTIONS, Pos};
 use std::collections::{HashMap, VecDeque};
 
-use crate::years::AdventDay;
+use crate::error::{Error, Result};
+use crate::library::parse::parse_number;
+use crate::library::validate::Check;
+use crate::years::{AdventDay, Context, Example, Param, Solution, Solver};
 
 pub struct Day18 {}
 
 impl AdventDay for Day18 {
-    fn solve(&self) {
-        let lines = self.get_input();
-        let byte_positions = parse_byte_positions(&lines);
-        let end_pos = Pos { x: 70, y: 70 };
-        println!(
-            "Part1 solution: {}",
-            part1(&byte_positions[..1024], &end_pos)
-        );
-        println!("Part2 solution: {}", part2(&byte_positions, &end_pos));
+    fn solve(&self, lines: &[String], ctx: &Context) -> Result<Solution> {
+        let end = ctx.param("end")?;
+        let end_pos = Pos { x: end, y: end };
+        let bytes: usize = ctx.param("bytes")?;
+        Solver::try_parse(ctx, || parse_byte_positions(lines))?
+            .part1(|byte_positions| {
+                part1(&byte_positions[..byte_positions.len().min(bytes)], &end_pos)
+            })
+            .part2(|byte_positions| part2(byte_positions, &end_pos))
     }
 
     fn get_input_path(&self) -> &str {
         "Inputs/2024/day18.txt"
     }
-}
 
-fn parse_byte_positions(lines: &[String]) -> Vec<Pos> {
-    let mut bytes = Vec::new();
-    for line in lines.iter() {
-        let mut num_str = line.split(",");
-        let x = num_str.next().unwrap().parse::<i32>().unwrap();
-        let y = num_str.next().unwrap().parse::<i32>().unwrap();
-        bytes.push(Pos { x, y });
+    fn examples(&self) -> &'static [Example] {
+        EXAMPLES
+    }
+
+    fn input_checks(&self) -> &'static [Check] {
+        &[Check::Lines(r"\d+,\d+")]
     }
 
-    bytes
+    fn params(&self) -> &'static [Param] {
+        &[
+            Param {
+                name: "end",
+                default: "70",
+            },
+            Param {
+                name: "bytes",
+                default: "1024",
+            },
+        ]
+    }
+}
+
+fn parse_byte_position(line: &str, line_idx: usize) -> Result<Pos> {
+    let Some((x, y)) = line.split_once(',') else {
+        return Err(Error::parse(line_idx, "expected '<x>,<y>'"));
+    };
+    Ok(Pos {
+        x: parse_number(line, x, line_idx)?,
+        y: parse_number(line, y, line_idx)?,
+    })
+}
+
+fn parse_byte_positions(lines: &[String]) -> Result<Vec<Pos>> {
+    lines
+        .iter()
+        .enumerate()
+        .map(|(line_idx, line)| parse_byte_position(line, line_idx))
+        .collect()
 }
 
 fn find_path(byte_positions: &[Pos], end_pos: &Pos) -> HashMap<Pos, usize> {
@@ -106,10 +136,10 @@ fn part2(byte_positions: &[Pos], end_pos: &Pos) -> String {
         let visited = find_path(&new_byte_positions, end_pos);
 
         if let Some(path) = get_path(&visited, end_pos) {
-            let mut j = i;
-            while !path.contains(&byte_positions[j]) {
-                j += 1;
-            }
+            let Some(j) = (i..byte_positions.len()).find(|&j| path.contains(&byte_positions[j]))
+            else {
+                return String::new();
+            };
             i = j;
         } else {
             let str =
@@ -121,13 +151,7 @@ fn part2(byte_positions: &[Pos], end_pos: &Pos) -> String {
     }
 }
 
-#[cfg(test)]
-mod tests {
-    use super::*;
-    use crate::library::*;
-
-    fn get_input() -> Vec<Pos> {
-        let input = r#"5,4
+const EXAMPLE: &str = r#"5,4
 4,2
 4,5
 3,0
@@ -153,8 +177,38 @@ mod tests {
 1,6
 2,0"#;
 
-        let lines = parse_lines(&input);
-        parse_byte_positions(&lines)
+const EXAMPLES: &[Example] = &[Example {
+    name: "example",
+    input: EXAMPLE,
+    part1: Some("22"),
+    part2: Some("6,1"),
+    params: &[("end", "6"), ("bytes", "12")],
+}];
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::library::*;
+
+    fn get_input() -> Vec<Pos> {
+        let lines = parse_lines(EXAMPLE);
+        parse_byte_positions(&lines).unwrap()
+    }
+
+    #[test]
+    fn test_parse_error() {
+        let error = parse_byte_positions(&parse_lines("5,4\n4,x")).unwrap_err();
+        assert!(
+            matches!(
+                error,
+                Error::Parse {
+                    line: 2,
+                    column: Some(3),
+                    ..
+                }
+            ),
+            "{error}"
+        );
     }
 
     #[test]
@@ -1,26 +1,33 @@
 use std::collections::HashMap;
 
-use crate::years::AdventDay;
+use crate::error::{Error, Result, offset_in};
+use crate::library::validate::Check;
+use crate::years::{AdventDay, Context, Example, Solution, Solver};
 
 pub struct Day19 {}
 
 impl AdventDay for Day19 {
-    fn solve(&self) {
-        let lines = self.get_input();
-        let (available_patterns, patterns_to_make) = parse_towels(&lines);
-        println!(
-            "Part1 solution: {}",
-            part1(&available_patterns, &patterns_to_make)
-        );
-        println!(
-            "Part2 solution: {}",
-            part2(&available_patterns, &patterns_to_make)
-        );
+    fn solve(&self, lines: &[String], ctx: &Context) -> Result<Solution> {
+        Solver::try_parse(ctx, || parse_towels(lines))?
+            .part1(|(available_patterns, patterns_to_make)| {
+                part1(available_patterns, patterns_to_make)
+            })
+            .part2(|(available_patterns, patterns_to_make)| {
+                part2(available_patterns, patterns_to_make)
+            })
     }
 
     fn get_input_path(&self) -> &str {
         "Inputs/2024/day19.txt"
     }
+
+    fn examples(&self) -> &'static [Example] {
+        EXAMPLES
+    }
+
+    fn input_checks(&self) -> &'static [Check] {
+        &[Check::Lines(r"[wubrg]+(, [wubrg]+)*")]
+    }
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
@@ -34,25 +41,42 @@ enum Color {
 
 type Towel = Vec<Color>;
 
-fn parse_towel(str: &str) -> Towel {
-    str.chars()
-        .map(|c| match c {
-            'w' => Color::White,
-            'u' => Color::Blue,
-            'b' => Color::Black,
-            'r' => Color::Red,
-            'g' => Color::Green,
-            c => panic!("Got unexpected color '{c}'"),
+fn parse_towel(line: &str, towel: &str, line_idx: usize) -> Result<Towel> {
+    let start = offset_in(line, towel);
+    towel
+        .char_indices()
+        .map(|(col_idx, c)| match c {
+            'w' => Ok(Color::White),
+            'u' => Ok(Color::Blue),
+            'b' => Ok(Color::Black),
+            'r' => Ok(Color::Red),
+            'g' => Ok(Color::Green),
+            c => Err(Error::parse_at(
+                line_idx,
+                start + col_idx,
+                format!("expected a color out of 'wubrg', got '{c}'"),
+            )),
         })
-        .collect::<Towel>()
+        .collect()
 }
 
-fn parse_towels(lines: &[String]) -> (Vec<Towel>, Vec<Towel>) {
-    let available_towel_patterns = lines[0].split(", ").map(parse_towel).collect();
-
-    let patterns_to_make = lines.iter().skip(2).map(|s| parse_towel(s)).collect();
-
-    (available_towel_patterns, patterns_to_make)
+fn parse_towels(lines: &[String]) -> Result<(Vec<Towel>, Vec<Towel>)> {
+    let Some(first_line) = lines.first() else {
+        return Err(Error::parse(0, "expected the available towel patterns"));
+    };
+    let available_towel_patterns = first_line
+        .split(", ")
+        .map(|towel| parse_towel(first_line, towel, 0))
+        .collect::<Result<_>>()?;
+
+    let patterns_to_make = lines
+        .iter()
+        .enumerate()
+        .skip(2)
+        .map(|(line_idx, line)| parse_towel(line, line, line_idx))
+        .collect::<Result<_>>()?;
+
+    Ok((available_towel_patterns, patterns_to_make))
 }
 
 fn part1(available_patterns: &[Towel], patterns_to_make: &[Towel]) -> usize {
@@ -116,13 +140,7 @@ fn part2(available_patterns: &[Towel], patterns_to_make: &[Towel]) -> usize {
     })
 }
 
-#[cfg(test)]
-mod tests {
-    use super::*;
-    use crate::library::*;
-
-    fn get_input() -> (Vec<Towel>, Vec<Towel>) {
-        let input = r#"r, wr, b, g, bwu, rb, gb, br
+const EXAMPLE: &str = r#"r, wr, b, g, bwu, rb, gb, br
 
 brwrr
 bggr
@@ -133,8 +151,22 @@ bwurrg
 brgr
 bbrgwb"#;
 
-        let lines = parse_lines(&input);
-        parse_towels(&lines)
+const EXAMPLES: &[Example] = &[Example {
+    name: "example",
+    input: EXAMPLE,
+    part1: Some("6"),
+    part2: Some("16"),
+    params: &[],
+}];
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::library::*;
+
+    fn get_input() -> (Vec<Towel>, Vec<Towel>) {
+        let lines = parse_lines(EXAMPLE);
+        parse_towels(&lines).unwrap()
     }
 
     #[test]
@@ -152,4 +184,18 @@ bbrgwb"#;
 
         Ok(())
     }
+
+    #[test]
+    fn test_parse_error() {
+        let lines = parse_lines("r, wr, bx\n\nbrwrr");
+        let error = parse_towels(&lines).unwrap_err();
+        assert!(matches!(
+            error,
+            Error::Parse {
+                line: 1,
+                column: Some(9),
+                ..
+            }
+        ));
+    }
 }
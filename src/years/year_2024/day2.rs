@@ -1,21 +1,48 @@
-use crate::years::AdventDay;
+use crate::error::Result;
+use crate::library::parse::parse_number;
+use crate::library::validate::Check;
+use crate::years::{AdventDay, Context, Example, Solution, Solver};
+use crate::{debug, trace};
 
 pub struct Day2 {}
 
 impl AdventDay for Day2 {
-    fn solve(&self) {
-        let lines = self.get_input();
-        println!("Part1 solution: {}", part1(&lines));
-        println!("Part2 solution: {}", part2(&lines));
+    fn solve(&self, lines: &[String], ctx: &Context) -> Result<Solution> {
+        Solver::try_parse(ctx, || parse_reports(lines))?
+            .part1(|reports| part1(reports))
+            .part2(|reports| part2(reports))
     }
 
     fn get_input_path(&self) -> &str {
         "Inputs/2024/day2.txt"
     }
+
+    fn examples(&self) -> &'static [Example] {
+        EXAMPLES
+    }
+
+    fn input_checks(&self) -> &'static [Check] {
+        &[Check::Lines(r"\d+( \d+)*")]
+    }
+}
+
+fn parse_reports(lines: &[String]) -> Result<Vec<Vec<i32>>> {
+    lines
+        .iter()
+        .enumerate()
+        .map(|(line_idx, line)| {
+            line.split_ascii_whitespace()
+                .map(|level| parse_number(line, level, line_idx))
+                .collect()
+        })
+        .collect()
 }
 
 fn safe_levels(nums: &[i32]) -> bool {
-    let increasing = (nums[1] - nums[0]).is_positive();
+    let [first, second, ..] = nums else {
+        return true;
+    };
+    let increasing = (second - first).is_positive();
 
     for (idx, curr) in (nums[..(nums.len() - 1)]).iter().enumerate() {
         let next: i32 = nums[idx + 1];
@@ -36,42 +63,28 @@ fn safe_levels(nums: &[i32]) -> bool {
     true
 }
 
-fn part1(lines: &Vec<String>) -> i32 {
+fn part1(reports: &[Vec<i32>]) -> i32 {
     let mut num_safe_reports = 0;
-    for line in lines {
-        let mut reports = Vec::new();
-        let report_nums = line.split_ascii_whitespace();
-
-        for report in report_nums.into_iter() {
-            reports.push(report.parse::<i32>().unwrap());
-        }
-
-        if safe_levels(&reports) {
+    for levels in reports {
+        if safe_levels(levels) {
             num_safe_reports += 1;
         }
     }
     num_safe_reports
 }
 
-fn part2(lines: &Vec<String>) -> i32 {
+fn part2(reports: &[Vec<i32>]) -> i32 {
     let mut num_safe_reports = 0;
-    for line in lines {
-        let mut reports = Vec::new();
-        let report_nums = line.split_ascii_whitespace();
-
-        for report in report_nums.into_iter() {
-            reports.push(report.parse::<i32>().unwrap());
-        }
-
-        println!("Checking {reports:?}");
-        if safe_levels(&reports) {
-            println!("{reports:?}\n");
+    for levels in reports {
+        trace!("Checking {levels:?}");
+        if safe_levels(levels) {
+            debug!("{levels:?} is safe");
             num_safe_reports += 1;
             continue;
         }
 
-        for skip_index in 0..reports.len() {
-            let subset: Vec<i32> = reports
+        for skip_index in 0..levels.len() {
+            let subset: Vec<i32> = levels
                 .iter()
                 .enumerate()
                 .filter(|(i, _)| *i != skip_index)
@@ -79,7 +92,7 @@ fn part2(lines: &Vec<String>) -> i32 {
                 .collect();
 
             if safe_levels(&subset) {
-                println!("{skip_index}: {subset:?}\n");
+                debug!("{levels:?} is safe without level {skip_index}: {subset:?}");
                 num_safe_reports += 1;
                 break;
             }
@@ -89,20 +102,46 @@ fn part2(lines: &Vec<String>) -> i32 {
     num_safe_reports
 }
 
-#[cfg(test)]
-mod tests {
-    use super::*;
-    use crate::library::*;
-
-    fn get_lines() -> Vec<String> {
-        let input = r#"7 6 4 2 1
+const EXAMPLE: &str = r#"7 6 4 2 1
 1 2 7 8 9
 9 7 6 2 1
 1 3 2 4 5
 8 6 4 4 1
 1 3 6 7 9"#;
 
-        parse_lines(&input)
+const EXAMPLES: &[Example] = &[Example {
+    name: "example",
+    input: EXAMPLE,
+    part1: Some("2"),
+    part2: Some("4"),
+    params: &[],
+}];
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::error::Error;
+    use crate::library::*;
+
+    fn get_lines() -> Vec<Vec<i32>> {
+        parse_reports(&parse_lines(EXAMPLE)).unwrap()
+    }
+
+    #[test]
+    fn test_parse_error() {
+        let error = parse_reports(&parse_lines("7 6 4\n1 2 x 8")).unwrap_err();
+        assert!(
+            matches!(
+                error,
+                Error::Parse {
+                    line: 2,
+                    column: Some(5),
+                    ..
+                }
+            ),
+            "{error}"
+        );
+        assert!(safe_levels(&[4]));
     }
 
     #[test]
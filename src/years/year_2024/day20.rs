@@ -1,21 +1,38 @@
 use super::{DIRECTIONS, Map, Pos};
 use std::collections::{HashMap, HashSet};
 
-use crate::years::AdventDay;
+use crate::error::{Error, Result};
+use crate::library::validate::Check;
+use crate::years::{AdventDay, Context, Example, Param, Solution, Solver};
 
 pub struct Day20 {}
 
 impl AdventDay for Day20 {
-    fn solve(&self) {
-        let lines = self.get_input();
-        let (start_pos, end_pos, map) = parse_map(&lines);
-        println!("Part1 solution: {}", part1(&start_pos, &end_pos, &map, 100));
-        println!("Part2 solution: {}", part2(&start_pos, &end_pos, &map, 100));
+    fn solve(&self, lines: &[String], ctx: &Context) -> Result<Solution> {
+        let time_to_save = ctx.param("time_to_save")?;
+        Solver::try_parse(ctx, || parse_map(lines))?
+            .part1(|(start_pos, end_pos, map)| part1(start_pos, end_pos, map, time_to_save))
+            .part2(|(start_pos, end_pos, map)| part2(start_pos, end_pos, map, time_to_save))
     }
 
     fn get_input_path(&self) -> &str {
         "Inputs/2024/day20.txt"
     }
+
+    fn examples(&self) -> &'static [Example] {
+        EXAMPLES
+    }
+
+    fn input_checks(&self) -> &'static [Check] {
+        &[Check::Chars("#.SE"), Check::SquareGrid]
+    }
+
+    fn params(&self) -> &'static [Param] {
+        &[Param {
+            name: "time_to_save",
+            default: "100",
+        }]
+    }
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
@@ -24,25 +41,29 @@ enum Space {
     Wall,
 }
 
-fn parse_map(lines: &[String]) -> (Pos, Pos, Map<Space>) {
+fn parse_map(lines: &[String]) -> Result<(Pos, Pos, Map<Space>)> {
     let mut start_pos = Pos { x: 0, y: 0 };
     let mut end_pos = Pos { x: 0, y: 0 };
 
-    let map = Map::new(lines, |c, pos| match c {
-        '.' => Space::Empty,
-        '#' => Space::Wall,
+    let map = Map::try_new(lines, |c, pos| match c {
+        '.' => Ok(Space::Empty),
+        '#' => Ok(Space::Wall),
         'S' => {
             start_pos = pos.clone();
-            Space::Empty
+            Ok(Space::Empty)
         }
         'E' => {
             end_pos = pos.clone();
-            Space::Empty
+            Ok(Space::Empty)
         }
-        c => panic!("Got unexpected char '{c}' when parsing map"),
-    });
-
-    (start_pos, end_pos, map)
+        c => Err(Error::parse_at(
+            pos.y as usize,
+            pos.x as usize,
+            format!("expected '.', '#', 'S' or 'E', got '{c}'"),
+        )),
+    })?;
+
+    Ok((start_pos, end_pos, map))
 }
 
 fn find_path_length(start_pos: &Pos, end_pos: &Pos, map: &Map<Space>) -> Vec<(Pos, usize)> {
@@ -122,13 +143,7 @@ fn part2(start_pos: &Pos, end_pos: &Pos, map: &Map<Space>, time_to_save: usize)
     })
 }
 
-#[cfg(test)]
-mod tests {
-    use super::*;
-    use crate::library::*;
-
-    fn get_map() -> (Pos, Pos, Map<Space>) {
-        let input = r#"###############
+const EXAMPLE: &str = r#"###############
 #...#...#.....#
 #.#.#.#.#.###.#
 #S#...#.#.#...#
@@ -144,8 +159,31 @@ mod tests {
 #...#...#...###
 ###############"#;
 
-        let lines = parse_lines(&input);
-        parse_map(&lines)
+const EXAMPLES: &[Example] = &[
+    Example {
+        name: "example",
+        input: EXAMPLE,
+        part1: Some("44"),
+        part2: None,
+        params: &[("time_to_save", "2")],
+    },
+    Example {
+        name: "example-part2",
+        input: EXAMPLE,
+        part1: None,
+        part2: Some("285"),
+        params: &[("time_to_save", "50")],
+    },
+];
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::library::*;
+
+    fn get_map() -> (Pos, Pos, Map<Space>) {
+        let lines = parse_lines(EXAMPLE);
+        parse_map(&lines).unwrap()
     }
 
     #[test]
@@ -164,4 +202,18 @@ mod tests {
 
         Ok(())
     }
+
+    #[test]
+    fn test_parse_error() {
+        let lines = parse_lines("#S.#\n#.x#");
+        let error = parse_map(&lines).err().unwrap();
+        assert!(matches!(
+            error,
+            Error::Parse {
+                line: 2,
+                column: Some(3),
+                ..
+            }
+        ));
+    }
 }
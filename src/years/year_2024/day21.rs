@@ -1,20 +1,54 @@
 use super::Pos;
 use std::collections::{HashMap, HashSet, VecDeque};
 
-use crate::years::AdventDay;
+use crate::error::{Error, Result};
+use crate::library::parse::parse_number;
+use crate::library::validate::Check;
+use crate::years::{AdventDay, Context, Example, Solution, Solver};
 
 pub struct Day21 {}
 
 impl AdventDay for Day21 {
-    fn solve(&self) {
-        let lines = self.get_input();
-        println!("Part1 solution: {}", part1(&lines));
-        println!("Part2 solution: {}", part2(&lines));
+    fn solve(&self, lines: &[String], ctx: &Context) -> Result<Solution> {
+        Solver::try_parse(ctx, || parse_codes(lines))?
+            .part1(|codes| part1(codes))
+            .part2(|codes| part2(codes))
     }
 
     fn get_input_path(&self) -> &str {
         "Inputs/2024/day21.txt"
     }
+
+    fn examples(&self) -> &'static [Example] {
+        EXAMPLES
+    }
+
+    fn input_checks(&self) -> &'static [Check] {
+        &[Check::Lines(r"\d{3}A")]
+    }
+}
+
+// Each code paired with its numeric part
+fn parse_codes(lines: &[String]) -> Result<Vec<(&str, usize)>> {
+    let mut codes = Vec::new();
+    for (line_idx, line) in lines.iter().enumerate() {
+        let Some(digits) = line.strip_suffix('A') else {
+            return Err(Error::parse_at(
+                line_idx,
+                line.len(),
+                "expected the code to end with 'A'",
+            ));
+        };
+        if let Some((col_idx, c)) = digits.char_indices().find(|(_, c)| !c.is_ascii_digit()) {
+            return Err(Error::parse_at(
+                line_idx,
+                col_idx,
+                format!("expected a digit, got '{c}'"),
+            ));
+        }
+        codes.push((line.as_str(), parse_number(line, digits, line_idx)?));
+    }
+    Ok(codes)
 }
 
 fn get_numeric_button_coord(c: char) -> Pos {
@@ -130,14 +164,10 @@ fn get_sequence_len(code_str: &str) -> usize {
     frozen_robot_sequence.chars().count()
 }
 
-fn get_code_val(code_str: &str) -> usize {
-    code_str[..code_str.len() - 1].parse::<usize>().unwrap()
-}
-
-fn part1(input: &[String]) -> usize {
-    input.iter().fold(0, |acc, line| {
-        acc + get_sequence_len(line) * get_code_val(line)
-    })
+fn part1(codes: &[(&str, usize)]) -> usize {
+    codes
+        .iter()
+        .fold(0, |acc, (code, value)| acc + get_sequence_len(code) * value)
 }
 
 type PossiblePaths = HashMap<(char, char), HashSet<String>>;
@@ -340,31 +370,35 @@ fn build_all_paths_numpad() -> PossiblePaths {
     possible_paths
 }
 
-fn part2(input: &[String]) -> usize {
-    input.iter().fold(0, |acc, line| {
-        acc + get_generic_sequence_len(line, 25) * get_code_val(line)
+fn part2(codes: &[(&str, usize)]) -> usize {
+    codes.iter().fold(0, |acc, (code, value)| {
+        acc + get_generic_sequence_len(code, 25) * value
     })
 }
 
-#[cfg(test)]
-mod tests {
-    use super::*;
-    use crate::library::*;
-
-    fn get_input() -> Vec<String> {
-        let input = r#"029A
+const EXAMPLE: &str = r#"029A
 980A
 179A
 456A
 379A"#;
 
-        parse_lines(&input)
-    }
+const EXAMPLES: &[Example] = &[Example {
+    name: "example",
+    input: EXAMPLE,
+    part1: Some("126384"),
+    part2: Some("154115708116294"),
+    params: &[],
+}];
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::library::*;
 
     #[test]
     fn test_part1() -> Result<(), String> {
-        let input = get_input();
-        assert_eq!(part1(&input), 126384);
+        let lines = parse_lines(EXAMPLE);
+        assert_eq!(part1(&parse_codes(&lines).unwrap()), 126384);
 
         Ok(())
     }
@@ -406,9 +440,34 @@ mod tests {
 
     #[test]
     fn test_part2() -> Result<(), String> {
-        let input = get_input();
-        assert_eq!(part2(&input), 154115708116294);
+        let lines = parse_lines(EXAMPLE);
+        assert_eq!(part2(&parse_codes(&lines).unwrap()), 154115708116294);
 
         Ok(())
     }
+
+    #[test]
+    fn test_parse_error() {
+        let lines = parse_lines("029A\n9x0A");
+        let error = parse_codes(&lines).unwrap_err();
+        assert!(matches!(
+            error,
+            Error::Parse {
+                line: 2,
+                column: Some(2),
+                ..
+            }
+        ));
+
+        let lines = parse_lines("029A\n980");
+        let error = parse_codes(&lines).unwrap_err();
+        assert!(matches!(
+            error,
+            Error::Parse {
+                line: 2,
+                column: Some(4),
+                ..
+            }
+        ));
+    }
 }
@@ -1,26 +1,40 @@
 use std::collections::HashMap;
 
-use crate::years::AdventDay;
+use crate::error::Result;
+use crate::library::parse::parse_number;
+use crate::library::validate::Check;
+use crate::years::{AdventDay, Context, Example, Solution, Solver};
 
 pub struct Day22 {}
 
 impl AdventDay for Day22 {
-    fn solve(&self) {
-        let lines = self.get_input();
-        let input = parse_initial_numbers(&lines);
-        println!("Part1 solution: {}", part1(&input));
-        println!("Part2 solution: {}", part2(&input));
+    fn solve(&self, lines: &[String], ctx: &Context) -> Result<Solution> {
+        Solver::try_parse(ctx, || parse_initial_numbers(lines))?
+            .part1(|input| part1(input))
+            .part2(|input| part2(input))
     }
 
     fn get_input_path(&self) -> &str {
         "Inputs/2024/day22.txt"
     }
+
+    fn examples(&self) -> &'static [Example] {
+        EXAMPLES
+    }
+
+    fn input_checks(&self) -> &'static [Check] {
+        &[Check::Lines(r"\d+")]
+    }
 }
 
 const ITERATIONS: usize = 2000;
 
-fn parse_initial_numbers(lines: &[String]) -> Vec<usize> {
-    lines.iter().map(|s| s.parse().unwrap()).collect()
+fn parse_initial_numbers(lines: &[String]) -> Result<Vec<usize>> {
+    lines
+        .iter()
+        .enumerate()
+        .map(|(line_idx, line)| parse_number(line, line, line_idx))
+        .collect()
 }
 
 fn mix_and_prune(current_number: usize, secret_number: usize) -> usize {
@@ -108,19 +122,58 @@ fn part2(input: &[usize]) -> usize {
     }
 }
 
+const EXAMPLE_PART1: &str = r#"1
+10
+100
+2024"#;
+
+const EXAMPLE_PART2: &str = r#"1
+2
+3
+2024"#;
+
+const EXAMPLES: &[Example] = &[
+    Example {
+        name: "part1",
+        input: EXAMPLE_PART1,
+        part1: Some("37327623"),
+        part2: None,
+        params: &[],
+    },
+    Example {
+        name: "part2",
+        input: EXAMPLE_PART2,
+        part1: None,
+        part2: Some("23"),
+        params: &[],
+    },
+];
+
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::error::Error;
     use crate::library::*;
 
     fn get_input() -> Vec<usize> {
-        let input = r#"1
-10
-100
-2024"#;
+        let lines = parse_lines(EXAMPLE_PART1);
+        parse_initial_numbers(&lines).unwrap()
+    }
 
-        let lines = parse_lines(&input);
-        parse_initial_numbers(&lines)
+    #[test]
+    fn test_parse_error() {
+        let error = parse_initial_numbers(&parse_lines("1\n10\n-100")).unwrap_err();
+        assert!(
+            matches!(
+                error,
+                Error::Parse {
+                    line: 3,
+                    column: Some(1),
+                    ..
+                }
+            ),
+            "{error}"
+        );
     }
 
     #[test]
@@ -146,13 +199,8 @@ mod tests {
     }
 
     fn get_input2() -> Vec<usize> {
-        let input = r#"1
-2
-3
-2024"#;
-
-        let lines = parse_lines(&input);
-        parse_initial_numbers(&lines)
+        let lines = parse_lines(EXAMPLE_PART2);
+        parse_initial_numbers(&lines).unwrap()
     }
 
     #[test]
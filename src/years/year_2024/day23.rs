@@ -1,23 +1,32 @@
 use std::collections::{HashMap, HashSet};
 
-use crate::years::AdventDay;
+use crate::error::{Error, Result};
+use crate::library::validate::Check;
+use crate::years::{AdventDay, Context, Example, Solution, Solver};
 
 pub struct Day23 {}
 
 impl AdventDay for Day23 {
-    fn solve(&self) {
-        let lines = self.get_input();
-        let input = parse_graphs(&lines);
-        println!("Part1 solution: {}", part1(&input));
-        println!("Part2 solution: {}", part2(&input));
+    fn solve(&self, lines: &[String], ctx: &Context) -> Result<Solution> {
+        Solver::try_parse(ctx, || parse_graphs(lines))?
+            .part1(part1)
+            .part2(part2)
     }
 
     fn get_input_path(&self) -> &str {
         "Inputs/2024/day23.txt"
     }
+
+    fn examples(&self) -> &'static [Example] {
+        EXAMPLES
+    }
+
+    fn input_checks(&self) -> &'static [Check] {
+        &[Check::Lines(r"[a-z]{2}-[a-z]{2}")]
+    }
 }
 
-fn parse_graphs(input: &[String]) -> HashMap<String, Vec<String>> {
+fn parse_graphs(input: &[String]) -> Result<HashMap<String, Vec<String>>> {
     let mut graph: HashMap<String, Vec<String>> = HashMap::new();
 
     let mut insert_into_graph = |a: &str, b: &str| {
@@ -30,15 +39,19 @@ fn parse_graphs(input: &[String]) -> HashMap<String, Vec<String>> {
         }
     };
 
-    for line in input {
-        let a = &line[..2];
-        let b = &line[(line.len() - 2)..];
+    for (line_idx, line) in input.iter().enumerate() {
+        let Some((a, b)) = line.split_once('-') else {
+            return Err(Error::parse(
+                line_idx,
+                "expected two computers like 'kh-tc'",
+            ));
+        };
 
         insert_into_graph(a, b);
         insert_into_graph(b, a);
     }
 
-    graph
+    Ok(graph)
 }
 
 fn part1(graph: &HashMap<String, Vec<String>>) -> usize {
@@ -141,13 +154,7 @@ fn part2(graph: &HashMap<String, Vec<String>>) -> String {
     largest_network_found.join(",")
 }
 
-#[cfg(test)]
-mod tests {
-    use super::*;
-    use crate::library::*;
-
-    fn get_input() -> HashMap<String, Vec<String>> {
-        let input = r#"kh-tc
+const EXAMPLE: &str = r#"kh-tc
 qp-kh
 de-cg
 ka-co
@@ -180,8 +187,22 @@ wh-qp
 tb-vc
 td-yn"#;
 
-        let lines = parse_lines(&input);
-        parse_graphs(&lines)
+const EXAMPLES: &[Example] = &[Example {
+    name: "example",
+    input: EXAMPLE,
+    part1: Some("7"),
+    part2: Some("co,de,ka,ta"),
+    params: &[],
+}];
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::library::*;
+
+    fn get_input() -> HashMap<String, Vec<String>> {
+        let lines = parse_lines(EXAMPLE);
+        parse_graphs(&lines).unwrap()
     }
 
     #[test]
@@ -199,4 +220,11 @@ td-yn"#;
 
         Ok(())
     }
+
+    #[test]
+    fn test_parse_error() {
+        let lines = parse_lines("kh-tc\nqp kh");
+        let error = parse_graphs(&lines).unwrap_err();
+        assert!(matches!(error, Error::Parse { line: 2, .. }));
+    }
 }
@@ -1,20 +1,31 @@
 use std::collections::HashMap;
 
-use crate::years::AdventDay;
+use crate::error::{Error, Result, offset_in};
+use crate::library::parse::{Record, split_sections};
+use crate::library::text::InputText;
+use crate::library::validate::Check;
+use crate::years::{AdventDay, Context, Example, Solution, Solver};
 
 pub struct Day24 {}
 
 impl AdventDay for Day24 {
-    fn solve(&self) {
-        let lines = self.get_input();
-        let (wires, gates) = parse_wires(&lines);
-        println!("Part1 solution: {}", part1(&wires, &gates));
-        println!("Part2 solution: {}", part2(&wires, &gates));
+    fn solve_input(&self, input: &InputText, ctx: &Context) -> Result<Solution> {
+        Solver::try_parse(ctx, || parse_wires(input))?
+            .part1(|(wires, gates)| part1(wires, gates))
+            .part2(|(wires, gates)| part2(wires, gates))
     }
 
     fn get_input_path(&self) -> &str {
         "Inputs/2024/day24.txt"
     }
+
+    fn examples(&self) -> &'static [Example] {
+        EXAMPLES
+    }
+
+    fn input_checks(&self) -> &'static [Check] {
+        &[Check::Lines(r"[a-z]\d\d: [01]|\w+ (AND|OR|XOR) \w+ -> \w+")]
+    }
 }
 
 type Wire = String;
@@ -34,40 +45,49 @@ struct Gate {
     res: Wire,
 }
 
-fn parse_wires(lines: &[String]) -> (HashMap<String, usize>, Vec<Gate>) {
-    let mut wires = HashMap::new();
-    let mut parsing_gates = false;
-    let mut gates = Vec::new();
-
-    for line in lines {
-        if line.is_empty() {
-            parsing_gates = true;
-            continue;
+fn parse_gate(line: &str, idx: usize) -> Result<Gate> {
+    let split = line.split_ascii_whitespace().collect::<Vec<&str>>();
+    let [a, op, b, "->", res] = split[..] else {
+        return Err(Error::parse(
+            idx,
+            "expected '<wire> <AND|OR|XOR> <wire> -> <wire>'",
+        ));
+    };
+    let op = match op {
+        "XOR" => GateType::Xor,
+        "AND" => GateType::And,
+        "OR" => GateType::Or,
+        s => {
+            return Err(Error::parse_at(
+                idx,
+                offset_in(line, s),
+                format!("unknown gate type '{s}'"),
+            ));
         }
+    };
 
-        if parsing_gates {
-            let mut split = line.split_ascii_whitespace();
-            let a = split.next().unwrap().to_string();
-            let op = match split.next().unwrap() {
-                "XOR" => GateType::Xor,
-                "AND" => GateType::And,
-                "OR" => GateType::Or,
-                s => panic!("Got unexpected string '{s}' when trying to parse a gate"),
-            };
-            let b = split.next().unwrap().to_string();
-            let res = split.nth(1).unwrap().to_string();
-
-            gates.push(Gate { op, a, b, res });
-        } else {
-            let mut split = line.split(": ");
-            wires.insert(
-                split.next().unwrap().to_string(),
-                split.next().unwrap().parse::<usize>().unwrap(),
-            );
-        }
-    }
+    Ok(Gate {
+        op,
+        a: a.to_string(),
+        b: b.to_string(),
+        res: res.to_string(),
+    })
+}
+
+fn parse_wires(input: &InputText) -> Result<(HashMap<String, usize>, Vec<Gate>)> {
+    let [wires, gates] = split_sections(input)?;
 
-    (wires, gates)
+    let wires = Record::parse(wires)?
+        .values::<usize>()?
+        .into_iter()
+        .map(|(wire, value)| (wire.to_string(), value))
+        .collect();
+    let gates = gates
+        .indexed()
+        .map(|(idx, line)| parse_gate(line, idx))
+        .collect::<Result<_>>()?;
+
+    Ok((wires, gates))
 }
 
 fn part1(wires: &HashMap<String, usize>, gates: &[Gate]) -> usize {
@@ -173,13 +193,7 @@ fn part2(_wires: &HashMap<String, usize>, gates: &[Gate]) -> String {
     falty_wires.join(",")
 }
 
-#[cfg(test)]
-mod tests {
-    use super::*;
-    use crate::library::*;
-
-    fn get_input_small() -> (HashMap<String, usize>, Vec<Gate>) {
-        let input: &str = r#"x00: 1
+const EXAMPLE_SMALL: &str = r#"x00: 1
 x01: 1
 x02: 1
 y00: 0
@@ -190,12 +204,7 @@ x00 AND y00 -> z00
 x01 XOR y01 -> z01
 x02 OR y02 -> z02"#;
 
-        let lines = parse_lines(&input);
-        parse_wires(&lines)
-    }
-
-    fn get_input_large() -> (HashMap<String, usize>, Vec<Gate>) {
-        let input: &str = r#"x00: 1
+const EXAMPLE_LARGE: &str = r#"x00: 1
 x01: 0
 x02: 1
 x03: 1
@@ -243,8 +252,59 @@ hwm AND bqk -> z03
 tgd XOR rvg -> z12
 tnw OR pbm -> gnj"#;
 
-        let lines = parse_lines(&input);
-        parse_wires(&lines)
+const EXAMPLES: &[Example] = &[
+    Example {
+        name: "small",
+        input: EXAMPLE_SMALL,
+        part1: Some("4"),
+        part2: None,
+        params: &[],
+    },
+    Example {
+        name: "large",
+        input: EXAMPLE_LARGE,
+        part1: Some("2024"),
+        part2: None,
+        params: &[],
+    },
+];
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn get_input_small() -> (HashMap<String, usize>, Vec<Gate>) {
+        let input = InputText::from(EXAMPLE_SMALL);
+        parse_wires(&input).unwrap()
+    }
+
+    fn get_input_large() -> (HashMap<String, usize>, Vec<Gate>) {
+        let input = InputText::from(EXAMPLE_LARGE);
+        parse_wires(&input).unwrap()
+    }
+
+    #[test]
+    fn test_parse_error() {
+        let input = InputText::from("x00: 1\n\nx00 NAND y00 -> z00");
+        let error = parse_wires(&input).unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "line 3, column 5: unknown gate type 'NAND'"
+        );
+
+        let input = InputText::from("x00: 1\n\nx00 AND y00 z00");
+        let error = parse_wires(&input).unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "line 3: expected '<wire> <AND|OR|XOR> <wire> -> <wire>'"
+        );
+
+        let input = InputText::from("x00: 1\ny00: 2x\n\nx00 AND y00 -> z00");
+        let error = parse_wires(&input).unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "line 2, column 6: invalid value '2x' for 'y00'"
+        );
     }
 
     #[test]
@@ -1,70 +1,77 @@
-use crate::years::AdventDay;
+use crate::error::{Error, Result};
+use crate::library::text::{InputText, Section};
+use crate::library::validate::Check;
+use crate::years::{AdventDay, Context, Example, Solution, Solver};
 
 pub struct Day25 {}
 
 impl AdventDay for Day25 {
-    fn solve(&self) {
-        let lines = self.get_input();
-        let (keys, locks) = parse_keys_and_locks(&lines);
-        println!("Part1 solution: {}", part1(&keys, &locks));
+    fn solve_input(&self, input: &InputText, ctx: &Context) -> Result<Solution> {
+        Solver::try_parse(ctx, || parse_keys_and_locks(input))?
+            .part1(|(keys, locks)| part1(keys, locks))
+            .finish()
     }
 
     fn get_input_path(&self) -> &str {
         "Inputs/2024/day25.txt"
     }
+
+    fn examples(&self) -> &'static [Example] {
+        EXAMPLES
+    }
+
+    fn input_checks(&self) -> &'static [Check] {
+        &[Check::Chars("#.")]
+    }
 }
 
 type Key = [i32; 5];
 type Lock = [i32; 5];
 
-fn parse_keys_and_locks(lines: &[String]) -> (Vec<Key>, Vec<Lock>) {
-    let mut keys = Vec::new();
-    let mut locks = Vec::new();
-
-    let mut key_or_lock = [0; 5];
-    let mut parsing_key = false;
-    let mut check_if_parsing_key = true;
-    for line in lines {
-        if line.is_empty() {
-            // Remove the first or last line
-            key_or_lock.iter_mut().for_each(|i| {
-                *i -= 1;
-            });
-
-            if parsing_key {
-                keys.push(key_or_lock);
-            } else {
-                locks.push(key_or_lock);
-            }
+fn parse_heights(section: &Section) -> Result<[i32; 5]> {
+    // Start at -1 so the full first or last row is not counted
+    let mut heights = [-1; 5];
 
-            key_or_lock = [0; 5];
-            check_if_parsing_key = true;
-            continue;
-        }
-        if check_if_parsing_key {
-            parsing_key = line.starts_with(".");
-            check_if_parsing_key = false;
+    for (row, line) in section.indexed() {
+        if line.len() != heights.len() {
+            return Err(Error::parse(
+                row,
+                format!("expected {} columns, found {}", heights.len(), line.len()),
+            ));
         }
 
-        line.chars()
-            .enumerate()
-            .filter(|(_, c)| *c == '#')
-            .for_each(|(idx, _)| {
-                key_or_lock[idx] += 1;
-            });
+        for (col, char) in line.chars().enumerate() {
+            match char {
+                '#' => heights[col] += 1,
+                '.' => {}
+                c => {
+                    return Err(Error::parse_at(
+                        row,
+                        col,
+                        format!("expected '#' or '.', got '{c}'"),
+                    ));
+                }
+            }
+        }
     }
 
-    key_or_lock.iter_mut().for_each(|i| {
-        *i -= 1;
-    });
+    Ok(heights)
+}
 
-    if parsing_key {
-        keys.push(key_or_lock);
-    } else {
-        locks.push(key_or_lock);
+fn parse_keys_and_locks(input: &InputText) -> Result<(Vec<Key>, Vec<Lock>)> {
+    let mut keys = Vec::new();
+    let mut locks = Vec::new();
+
+    for section in input.sections() {
+        let heights = parse_heights(&section)?;
+        if section.text.starts_with('.') {
+            keys.push(heights);
+        } else {
+            locks.push(heights);
+        }
     }
 
-    (keys, locks)
+    Ok((keys, locks))
 }
 
 fn part1(keys: &[Key], locks: &[Lock]) -> usize {
@@ -80,13 +87,7 @@ fn part1(keys: &[Key], locks: &[Lock]) -> usize {
     keys_that_fit
 }
 
-#[cfg(test)]
-mod tests {
-    use super::*;
-    use crate::library::*;
-
-    fn get_input() -> (Vec<Key>, Vec<Lock>) {
-        let input: &str = r#"#####
+const EXAMPLE: &str = r#"#####
 .####
 .####
 .####
@@ -126,8 +127,21 @@ mod tests {
 #.#.#
 #####"#;
 
-        let lines = parse_lines(&input);
-        parse_keys_and_locks(&lines)
+const EXAMPLES: &[Example] = &[Example {
+    name: "example",
+    input: EXAMPLE,
+    part1: Some("3"),
+    part2: None,
+    params: &[],
+}];
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn get_input() -> (Vec<Key>, Vec<Lock>) {
+        let input = InputText::from(EXAMPLE);
+        parse_keys_and_locks(&input).unwrap()
     }
 
     #[test]
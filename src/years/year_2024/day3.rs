@@ -1,19 +1,24 @@
-use crate::years::AdventDay;
+use crate::error::Result;
+use crate::years::{AdventDay, Context, Example, Solution, Solver};
 use regex::Regex;
 use std::num::ParseIntError;
 
 pub struct Day3 {}
 
 impl AdventDay for Day3 {
-    fn solve(&self) {
-        let lines = self.get_input();
-        println!("Part1 solution: {}", part1(&lines));
-        println!("Part2 solution: {}", part2(&lines));
+    fn solve(&self, lines: &[String], ctx: &Context) -> Result<Solution> {
+        Solver::new(ctx, lines)
+            .part1(|lines| part1(lines))
+            .part2(|lines| part2(lines))
     }
 
     fn get_input_path(&self) -> &str {
         "Inputs/2024/day3.txt"
     }
+
+    fn examples(&self) -> &'static [Example] {
+        EXAMPLES
+    }
 }
 
 fn parse_and_mul(a: &str, b: &str) -> Result<i32, ParseIntError> {
@@ -63,15 +68,36 @@ fn part2(lines: &[String]) -> i32 {
     result
 }
 
+const EXAMPLE_PART1: &str =
+    r"xmul(2,4)%&mul[3,7]!@^do_not_mul(5,5)+mul(32,64]then(mul(11,8)mul(8,5))";
+
+const EXAMPLE_PART2: &str =
+    r"xmul(2,4)&mul[3,7]!^don't()_mul(5,5)+mul(32,64](mul(11,8)undo()?mul(8,5))";
+
+const EXAMPLES: &[Example] = &[
+    Example {
+        name: "part1",
+        input: EXAMPLE_PART1,
+        part1: Some("161"),
+        part2: None,
+        params: &[],
+    },
+    Example {
+        name: "part2",
+        input: EXAMPLE_PART2,
+        part1: None,
+        part2: Some("48"),
+        params: &[],
+    },
+];
+
 #[cfg(test)]
 mod tests {
     use super::*;
 
     #[test]
     fn test_part1() -> Result<(), String> {
-        let lines = vec![
-            r"xmul(2,4)%&mul[3,7]!@^do_not_mul(5,5)+mul(32,64]then(mul(11,8)mul(8,5))".to_string(),
-        ];
+        let lines = vec![EXAMPLE_PART1.to_string()];
         assert_eq!(part1(&lines), 161);
 
         Ok(())
@@ -79,10 +105,7 @@ mod tests {
 
     #[test]
     fn test_part2() -> Result<(), String> {
-        let lines = vec![
-            r"xmul(2,4)&mul[3,7]!^don't()_mul(5,5)+mul(32,64](mul(11,8)undo()?mul(8,5))"
-                .to_string(),
-        ];
+        let lines = vec![EXAMPLE_PART2.to_string()];
         assert_eq!(part2(&lines), 48);
 
         Ok(())
@@ -1,17 +1,27 @@
-use crate::years::AdventDay;
+use crate::error::Result;
+use crate::library::validate::Check;
+use crate::years::{AdventDay, Context, Example, Solution, Solver};
 
 pub struct Day4 {}
 
 impl AdventDay for Day4 {
-    fn solve(&self) {
-        let lines = self.get_input();
-        println!("Part1 solution: {}", part1(&lines));
-        println!("Part2 solution: {}", part2(&lines));
+    fn solve(&self, lines: &[String], ctx: &Context) -> Result<Solution> {
+        Solver::new(ctx, lines)
+            .part1(|lines| part1(lines))
+            .part2(|lines| part2(lines))
     }
 
     fn get_input_path(&self) -> &str {
         "Inputs/2024/day4.txt"
     }
+
+    fn examples(&self) -> &'static [Example] {
+        EXAMPLES
+    }
+
+    fn input_checks(&self) -> &'static [Check] {
+        &[Check::Chars("XMAS"), Check::Grid]
+    }
 }
 
 fn build_transposed_list(lines: &[String]) -> Vec<String> {
@@ -142,13 +152,7 @@ fn part2(lines: &[String]) -> usize {
     matches
 }
 
-#[cfg(test)]
-mod tests {
-    use super::*;
-    use crate::library::*;
-
-    fn get_lines() -> Vec<String> {
-        let input = r#"MMMSXXMASM
+const EXAMPLE: &str = r#"MMMSXXMASM
 MSAMXMSMSA
 AMXSXMAAMM
 MSAMASMSMX
@@ -159,7 +163,21 @@ SAXAMASAAA
 MAMMMXMMMM
 MXMXAXMASX"#;
 
-        parse_lines(&input)
+const EXAMPLES: &[Example] = &[Example {
+    name: "example",
+    input: EXAMPLE,
+    part1: Some("18"),
+    part2: Some("9"),
+    params: &[],
+}];
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::library::*;
+
+    fn get_lines() -> Vec<String> {
+        parse_lines(EXAMPLE)
     }
 
     #[test]
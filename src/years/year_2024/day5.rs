@@ -1,61 +1,65 @@
-use crate::years::AdventDay;
+use crate::error::{Error, Result};
+use crate::library::parse::{parse_number, split_sections};
+use crate::library::text::InputText;
+use crate::library::validate::Check;
+use crate::years::{AdventDay, Context, Example, Solution, Solver};
 use std::{cmp::Ordering, collections::HashMap};
 
 pub struct Day5 {}
 
 impl AdventDay for Day5 {
-    fn solve(&self) {
-        let lines = self.get_input();
-        let input = parse_input(&lines);
-        println!("Part1 solution: {}", part1(&input.0, &input.1));
-        println!("Part2 solution: {}", part2(&input.0, &input.1));
+    fn solve_input(&self, input: &InputText, ctx: &Context) -> Result<Solution> {
+        Solver::try_parse(ctx, || parse_input(input))?
+            .part1(|(rules, pages)| part1(rules, pages))
+            .part2(|(rules, pages)| part2(rules, pages))
     }
 
     fn get_input_path(&self) -> &str {
         "Inputs/2024/day5.txt"
     }
+
+    fn examples(&self) -> &'static [Example] {
+        EXAMPLES
+    }
+
+    fn input_checks(&self) -> &'static [Check] {
+        &[Check::Lines(r"\d+\|\d+|\d+(,\d+)*")]
+    }
 }
 
-fn parse_page_rules(line: &str) -> (u32, u32) {
-    let pages: Vec<&str> = line.split('|').collect();
-    // Assuming only two pages
-    let first_page = pages[0].parse::<u32>().unwrap();
-    let second_page = pages[1].parse::<u32>().unwrap();
-    (first_page, second_page)
+type Rules = HashMap<u32, Vec<u32>>;
+
+fn parse_page_rule(line: &str, idx: usize) -> Result<(u32, u32)> {
+    let (first, second) = line
+        .split_once('|')
+        .ok_or_else(|| Error::parse(idx, "expected '<page>|<page>'"))?;
+    Ok((
+        parse_number(line, first, idx)?,
+        parse_number(line, second, idx)?,
+    ))
 }
 
-fn parse_pages_to_produce(line: &str) -> Vec<u32> {
+fn parse_pages_to_produce(line: &str, idx: usize) -> Result<Vec<u32>> {
     line.split(',')
-        .collect::<Vec<&str>>()
-        .iter()
-        .map(|v| v.parse::<u32>().unwrap())
+        .map(|page| parse_number(line, page, idx))
         .collect()
 }
 
-fn parse_input(lines: &[String]) -> (HashMap<u32, Vec<u32>>, Vec<Vec<u32>>) {
-    let mut page_ordering_rules: HashMap<u32, Vec<u32>> = HashMap::new();
-    let mut pages_to_produce: Vec<Vec<u32>> = vec![];
+fn parse_input(input: &InputText) -> Result<(Rules, Vec<Vec<u32>>)> {
+    let [rules, updates] = split_sections(input)?;
 
-    let mut parsing_rules = true;
-    for line in lines {
-        if line.is_empty() {
-            parsing_rules = false;
-            continue;
-        }
-
-        if parsing_rules {
-            let (a, b) = parse_page_rules(line);
-            if let Some(val) = page_ordering_rules.get_mut(&a) {
-                val.push(b);
-            } else {
-                page_ordering_rules.insert(a, vec![b]);
-            }
-        } else {
-            pages_to_produce.push(parse_pages_to_produce(line));
-        }
+    let mut page_ordering_rules = Rules::new();
+    for (idx, line) in rules.indexed() {
+        let (a, b) = parse_page_rule(line, idx)?;
+        page_ordering_rules.entry(a).or_default().push(b);
     }
 
-    (page_ordering_rules, pages_to_produce)
+    let pages_to_produce = updates
+        .indexed()
+        .map(|(idx, line)| parse_pages_to_produce(line, idx))
+        .collect::<Result<_>>()?;
+
+    Ok((page_ordering_rules, pages_to_produce))
 }
 
 fn check_correct_pages(pages: &[u32], rules: &HashMap<u32, Vec<u32>>) -> usize {
@@ -103,13 +107,7 @@ fn part2(page_ordering_rules: &HashMap<u32, Vec<u32>>, pages_to_produce: &Vec<Ve
     result
 }
 
-#[cfg(test)]
-mod tests {
-    use super::*;
-    use crate::library::*;
-
-    fn get_lines() -> (HashMap<u32, Vec<u32>>, Vec<Vec<u32>>) {
-        let input = r#"47|53
+const EXAMPLE: &str = r#"47|53
 97|13
 97|61
 97|47
@@ -138,7 +136,32 @@ mod tests {
 61,13,29
 97,13,75,29,47"#;
 
-        parse_input(&parse_lines(&input))
+const EXAMPLES: &[Example] = &[Example {
+    name: "example",
+    input: EXAMPLE,
+    part1: Some("143"),
+    part2: Some("123"),
+    params: &[],
+}];
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn get_lines() -> (Rules, Vec<Vec<u32>>) {
+        parse_input(&InputText::from(EXAMPLE)).unwrap()
+    }
+
+    #[test]
+    fn test_parse_error() {
+        let error = parse_input(&InputText::from("47|53\n97-13\n\n75,47")).unwrap_err();
+        assert_eq!(error.to_string(), "line 2: expected '<page>|<page>'");
+
+        let error = parse_input(&InputText::from("47|53\n\n75,x7")).unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "line 3, column 4: expected a number, got 'x7'"
+        );
     }
 
     #[test]
@@ -1,21 +1,30 @@
 use super::{DOWN, Dir, LEFT, Map, Pos, RIGHT, UP};
 use std::collections::HashSet;
 
-use crate::years::AdventDay;
+use crate::error::{Error, Result};
+use crate::library::validate::Check;
+use crate::years::{AdventDay, Context, Example, Solution, Solver};
 
 pub struct Day6 {}
 
 impl AdventDay for Day6 {
-    fn solve(&self) {
-        let lines = self.get_input();
-        let (mut map, start_pos) = parse_map(&lines);
-        println!("Part1 solution: {}", part1(&map, &start_pos));
-        println!("Part2 solution: {}", part2(&mut map, &start_pos));
+    fn solve(&self, lines: &[String], ctx: &Context) -> Result<Solution> {
+        Solver::try_parse(ctx, || parse_map(lines))?
+            .part1(|(map, start_pos)| part1(map, start_pos))
+            .part2(|(map, start_pos)| part2(&mut map.clone(), start_pos, ctx))
     }
 
     fn get_input_path(&self) -> &str {
         "Inputs/2024/day6.txt"
     }
+
+    fn examples(&self) -> &'static [Example] {
+        EXAMPLES
+    }
+
+    fn input_checks(&self) -> &'static [Check] {
+        &[Check::Chars(".#^"), Check::SquareGrid]
+    }
 }
 
 fn turn_right(dir: &mut &Dir) {
@@ -28,20 +37,22 @@ fn turn_right(dir: &mut &Dir) {
     }
 }
 
-fn parse_map(lines: &[String]) -> (Map<u8>, Pos) {
+fn parse_map(lines: &[String]) -> Result<(Map<u8>, Pos)> {
     let mut start_pos = Pos { x: 0, y: 0 };
-    let map = Map::new(lines, |char, pos| {
-        if char == '^' {
+    let map = Map::try_new(lines, |char, pos| match char {
+        '^' => {
             start_pos = pos.to_owned();
-            return b'.';
-        }
-        match char {
-            '#' => b'#',
-            '.' => b'.',
-            _ => panic!("Got unexpected char '{char}' while parsing map"),
+            Ok(b'.')
         }
-    });
-    (map, start_pos)
+        '#' => Ok(b'#'),
+        '.' => Ok(b'.'),
+        _ => Err(Error::parse_at(
+            pos.y as usize,
+            pos.x as usize,
+            format!("expected '.', '#' or '^', got '{char}'"),
+        )),
+    })?;
+    Ok((map, start_pos))
 }
 
 fn find_visited_positions(map: &Map<u8>, start_pos: &Pos, dir: &Dir) -> Option<HashSet<Pos>> {
@@ -76,7 +87,7 @@ fn part1(map: &Map<u8>, start_pos: &Pos) -> usize {
     visited_positions.unwrap().len()
 }
 
-fn part2(map: &mut Map<u8>, start_pos: &Pos) -> usize {
+fn part2(map: &mut Map<u8>, start_pos: &Pos, ctx: &Context) -> usize {
     let mut curr_pos = start_pos.clone();
     let mut dir = UP;
 
@@ -86,6 +97,9 @@ fn part2(map: &mut Map<u8>, start_pos: &Pos) -> usize {
     let mut result = 0;
 
     while let Some(val) = map.next(&curr_pos, dir) {
+        if ctx.cancelled() {
+            break;
+        }
         match val {
             b'.' => {
                 let next_pos = &curr_pos + dir;
@@ -113,13 +127,7 @@ fn part2(map: &mut Map<u8>, start_pos: &Pos) -> usize {
     result
 }
 
-#[cfg(test)]
-mod tests {
-    use super::*;
-    use crate::library::*;
-
-    fn get_input() -> (Map<u8>, Pos) {
-        let input = r#"....#.....
+const EXAMPLE: &str = r#"....#.....
 .........#
 ..........
 ..#.......
@@ -130,7 +138,21 @@ mod tests {
 #.........
 ......#..."#;
 
-        parse_map(&parse_lines(&input))
+const EXAMPLES: &[Example] = &[Example {
+    name: "example",
+    input: EXAMPLE,
+    part1: Some("41"),
+    part2: Some("6"),
+    params: &[],
+}];
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::library::*;
+
+    fn get_input() -> (Map<u8>, Pos) {
+        parse_map(&parse_lines(EXAMPLE)).unwrap()
     }
 
     #[test]
@@ -144,8 +166,22 @@ mod tests {
     #[test]
     fn test_part2() -> Result<(), String> {
         let (mut map, start_pos) = get_input();
-        assert_eq!(part2(&mut map, &start_pos), 6);
+        assert_eq!(part2(&mut map, &start_pos, &Context::default()), 6);
 
         Ok(())
     }
+
+    #[test]
+    fn test_parse_error() {
+        let lines = parse_lines("..#.\n.^.x");
+        let error = parse_map(&lines).err().unwrap();
+        assert!(matches!(
+            error,
+            Error::Parse {
+                line: 2,
+                column: Some(4),
+                ..
+            }
+        ));
+    }
 }
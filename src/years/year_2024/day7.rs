@@ -1,36 +1,56 @@
-use crate::years::AdventDay;
+use crate::error::{Error, Result};
+use crate::library::parse::parse_number;
+use crate::library::validate::Check;
+use crate::years::{AdventDay, Context, Example, Solution, Solver};
 
 pub struct Day7 {}
 
 impl AdventDay for Day7 {
-    fn solve(&self) {
-        let lines = self.get_input();
-        let equations = parse_equations(&lines);
-        println!("Part1 solution: {}", part1(&equations));
-        println!("Part2 solution: {}", part2(&equations));
+    fn solve(&self, lines: &[String], ctx: &Context) -> Result<Solution> {
+        Solver::try_parse(ctx, || parse_equations(lines))?
+            .part1(|equations| part1(equations))
+            .part2(|equations| part2(equations))
     }
 
     fn get_input_path(&self) -> &str {
         "Inputs/2024/day7.txt"
     }
-}
 
-fn parse_equation(line: &str) -> (usize, Vec<usize>) {
-    let mut split = line.split(':');
+    fn examples(&self) -> &'static [Example] {
+        EXAMPLES
+    }
+
+    fn input_checks(&self) -> &'static [Check] {
+        &[Check::Lines(r"\d+:( \d+)+")]
+    }
+}
 
-    let answer = split.next().unwrap().parse::<usize>().unwrap();
+fn parse_equation(line: &str, line_idx: usize) -> Result<(usize, Vec<usize>)> {
+    let Some((answer, numbers)) = line.split_once(':') else {
+        return Err(Error::parse(line_idx, "expected '<answer>: <n> <n> ...'"));
+    };
 
-    let mut numbers = Vec::new();
-    if let Some(numbers_string) = split.next() {
-        for number_string in numbers_string.split_ascii_whitespace() {
-            numbers.push(number_string.parse::<usize>().unwrap());
-        }
+    let answer = parse_number(line, answer, line_idx)?;
+    let numbers = numbers
+        .split_ascii_whitespace()
+        .map(|number| parse_number(line, number, line_idx))
+        .collect::<Result<Vec<usize>>>()?;
+    if numbers.is_empty() {
+        return Err(Error::parse_at(
+            line_idx,
+            line.len(),
+            "expected at least one number after ':'",
+        ));
     }
-    (answer, numbers)
+    Ok((answer, numbers))
 }
 
-fn parse_equations(lines: &[String]) -> Vec<(usize, Vec<usize>)> {
-    lines.iter().map(|line| parse_equation(line)).collect()
+fn parse_equations(lines: &[String]) -> Result<Vec<(usize, Vec<usize>)>> {
+    lines
+        .iter()
+        .enumerate()
+        .map(|(line_idx, line)| parse_equation(line, line_idx))
+        .collect()
 }
 
 fn try_solve_equation_recursive(
@@ -106,13 +126,7 @@ fn part2(equations: &[(usize, Vec<usize>)]) -> usize {
         .fold(0, |acc, result| acc + result.unwrap())
 }
 
-#[cfg(test)]
-mod tests {
-    use super::*;
-    use crate::library::*;
-
-    fn get_equations() -> Vec<(usize, Vec<usize>)> {
-        let input = r#"190: 10 19
+const EXAMPLE: &str = r#"190: 10 19
 3267: 81 40 27
 83: 17 5
 156: 15 6
@@ -122,8 +136,39 @@ mod tests {
 21037: 9 7 18 13
 292: 11 6 16 20"#;
 
-        let lines = parse_lines(&input);
-        parse_equations(&lines)
+const EXAMPLES: &[Example] = &[Example {
+    name: "example",
+    input: EXAMPLE,
+    part1: Some("3749"),
+    part2: Some("11387"),
+    params: &[],
+}];
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::library::*;
+
+    fn get_equations() -> Vec<(usize, Vec<usize>)> {
+        let lines = parse_lines(EXAMPLE);
+        parse_equations(&lines).unwrap()
+    }
+
+    #[test]
+    fn test_parse_error() {
+        let lines = parse_lines("190: 10 19\n99999999999999999999999: 1 2");
+        let error = parse_equations(&lines).unwrap_err();
+        assert!(
+            matches!(
+                error,
+                Error::Parse {
+                    line: 2,
+                    column: Some(1),
+                    ..
+                }
+            ),
+            "{error}"
+        );
     }
 
     #[test]
@@ -1,41 +1,51 @@
 use super::Pos;
-use crate::years::AdventDay;
+use crate::error::{Error, Result};
+use crate::library::validate::Check;
+use crate::years::{AdventDay, Context, Example, Solution, Solver};
 use std::collections::{HashMap, HashSet};
 
 pub struct Day8 {}
 
 impl AdventDay for Day8 {
-    fn solve(&self) {
-        let lines = self.get_input();
-        let (map_size, antenna_positions) = parse_map(&lines);
-        println!("Part1 solution: {}", part1(&map_size, &antenna_positions));
-        println!("Part2 solution: {}", part2(&map_size, &antenna_positions));
+    fn solve(&self, lines: &[String], ctx: &Context) -> Result<Solution> {
+        Solver::try_parse(ctx, || parse_map(lines))?
+            .part1(|(map_size, antenna_positions)| part1(map_size, antenna_positions))
+            .part2(|(map_size, antenna_positions)| part2(map_size, antenna_positions))
     }
 
     fn get_input_path(&self) -> &str {
         "Inputs/2024/day8.txt"
     }
+
+    fn examples(&self) -> &'static [Example] {
+        EXAMPLES
+    }
+
+    fn input_checks(&self) -> &'static [Check] {
+        &[Check::Lines(r"[.0-9A-Za-z]+"), Check::SquareGrid]
+    }
 }
 
 fn valid_pos(pos: &Pos, map_size: &Pos) -> bool {
     pos.x >= 0 && pos.y >= 0 && pos.x < map_size.x && pos.y < map_size.y
 }
 
-fn parse_map(lines: &[String]) -> (Pos, HashMap<char, Vec<Pos>>) {
+fn parse_map(lines: &[String]) -> Result<(Pos, HashMap<char, Vec<Pos>>)> {
     let map_size = Pos {
-        x: lines[0].len() as i32,
+        x: lines.first().map_or(0, |line| line.len()) as i32,
         y: lines.len() as i32,
     };
     let mut antenna_positions: HashMap<char, Vec<Pos>> = HashMap::new();
 
     for (row, line) in lines.iter().enumerate() {
-        for (col, _) in line
-            .as_bytes()
-            .iter()
-            .enumerate()
-            .filter(|(_, v)| **v != b'.')
-        {
-            let char = line.chars().nth(col).unwrap();
+        for (col, char) in line.char_indices().filter(|(_, c)| *c != '.') {
+            if !char.is_ascii_alphanumeric() {
+                return Err(Error::parse_at(
+                    row,
+                    col,
+                    format!("expected an antenna or '.', got '{char}'"),
+                ));
+            }
             let row = row as i32;
             let col = col as i32;
             if let Some(antennas) = antenna_positions.get_mut(&char) {
@@ -50,7 +60,7 @@ fn parse_map(lines: &[String]) -> (Pos, HashMap<char, Vec<Pos>>) {
         v.sort();
     }
 
-    (map_size, antenna_positions)
+    Ok((map_size, antenna_positions))
 }
 
 fn calculate_antinode_positions(
@@ -110,13 +120,7 @@ fn part2(map_size: &Pos, antenna_positions: &HashMap<char, Vec<Pos>>) -> usize {
     calculate_antinode_positions(map_size, antenna_positions, true)
 }
 
-#[cfg(test)]
-mod tests {
-    use super::*;
-    use crate::library::*;
-
-    fn get_equations() -> (Pos, HashMap<char, Vec<Pos>>) {
-        let input = r#"............
+const EXAMPLE: &str = r#"............
 ........0...
 .....0......
 .......0....
@@ -129,8 +133,22 @@ mod tests {
 ............
 ............"#;
 
-        let lines = parse_lines(&input);
-        parse_map(&lines)
+const EXAMPLES: &[Example] = &[Example {
+    name: "example",
+    input: EXAMPLE,
+    part1: Some("14"),
+    part2: Some("34"),
+    params: &[],
+}];
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::library::*;
+
+    fn get_equations() -> (Pos, HashMap<char, Vec<Pos>>) {
+        let lines = parse_lines(EXAMPLE);
+        parse_map(&lines).unwrap()
     }
 
     #[test]
@@ -148,4 +166,18 @@ mod tests {
 
         Ok(())
     }
+
+    #[test]
+    fn test_parse_error() {
+        let lines = parse_lines("..a.\n.#..");
+        let error = parse_map(&lines).unwrap_err();
+        assert!(matches!(
+            error,
+            Error::Parse {
+                line: 2,
+                column: Some(2),
+                ..
+            }
+        ));
+    }
 }
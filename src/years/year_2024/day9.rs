@@ -1,33 +1,46 @@
-use super::parse_file;
-use crate::years::AdventDay;
+use crate::error::{Error, Result};
+use crate::library::validate::Check;
+use crate::years::{AdventDay, Context, Example, Solution, Solver};
 
 pub struct Day9 {}
 
 impl AdventDay for Day9 {
-    fn solve(&self) {
-        if let Ok(line_string) = parse_file(self.get_input_path()) {
-            let disk = parse_disk_string(&line_string);
-            println!("Part1 solution: {}", part1(&disk));
-            println!("Part2 solution: {}", part2(&line_string));
-        } else {
-            println!("Could not parse file");
-        }
+    fn solve(&self, lines: &[String], ctx: &Context) -> Result<Solution> {
+        Solver::try_parse(ctx, || parse_digits(lines))?
+            .part1(|digits| part1(&parse_disk_string(digits)))
+            .part2(|digits| part2(digits))
     }
 
     fn get_input_path(&self) -> &str {
         "Inputs/2024/day9.txt"
     }
+
+    fn examples(&self) -> &'static [Example] {
+        EXAMPLES
+    }
+
+    fn input_checks(&self) -> &'static [Check] {
+        &[Check::Lines(r"\d+")]
+    }
 }
 
-fn parse_disk_string(str: &str) -> Vec<Option<u32>> {
-    let parsed_disk_representation = str
-        .chars()
-        .map(|c| c.to_string().parse::<u32>().unwrap())
-        .collect::<Vec<u32>>();
+fn parse_digits(lines: &[String]) -> Result<Vec<u32>> {
+    let mut digits = Vec::new();
+    for (line_idx, line) in lines.iter().enumerate() {
+        for (col_idx, c) in line.char_indices() {
+            let digit = c.to_digit(10).ok_or_else(|| {
+                Error::parse_at(line_idx, col_idx, format!("expected a digit, got '{c}'"))
+            })?;
+            digits.push(digit);
+        }
+    }
+    Ok(digits)
+}
 
+fn parse_disk_string(digits: &[u32]) -> Vec<Option<u32>> {
     let mut disk_representation = Vec::new();
     let mut file_id = 0;
-    for (idx, val) in parsed_disk_representation.iter().enumerate() {
+    for (idx, val) in digits.iter().enumerate() {
         let block = if idx % 2 == 0 { Some(file_id) } else { None };
         disk_representation.append(&mut vec![block; *val as usize]);
 
@@ -93,19 +106,15 @@ fn merge_empty_spaces(disk: &mut Vec<Block>) {
     }
 }
 
-fn part2(str: &str) -> usize {
-    let parsed_disk_representation = str
-        .chars()
-        .map(|c| c.to_string().parse::<usize>().unwrap())
-        .collect::<Vec<usize>>();
-
+fn part2(digits: &[u32]) -> usize {
     let mut disk_representation = Vec::new();
     let mut file_id = 0;
-    for (idx, val) in parsed_disk_representation.iter().enumerate() {
+    for (idx, val) in digits.iter().enumerate() {
+        let val = *val as usize;
         if idx % 2 == 0 {
-            disk_representation.push(Block::File(file_id, *val));
-        } else if *val > 0 {
-            disk_representation.push(Block::Empty(*val));
+            disk_representation.push(Block::File(file_id, val));
+        } else if val > 0 {
+            disk_representation.push(Block::Empty(val));
         };
 
         if idx % 2 == 0 {
@@ -176,17 +185,27 @@ fn part2(str: &str) -> usize {
     result
 }
 
+const EXAMPLE: &str = r#"2333133121414131402"#;
+
+const EXAMPLES: &[Example] = &[Example {
+    name: "example",
+    input: EXAMPLE,
+    part1: Some("1928"),
+    part2: Some("2858"),
+    params: &[],
+}];
+
 #[cfg(test)]
 mod tests {
     use super::*;
 
-    fn get_line() -> String {
-        r#"2333133121414131402"#.to_string()
+    fn get_digits() -> Vec<u32> {
+        parse_digits(&[EXAMPLE.to_string()]).unwrap()
     }
 
     #[test]
     fn test_part1() -> Result<(), String> {
-        let disk = parse_disk_string(&get_line());
+        let disk = parse_disk_string(&get_digits());
         assert_eq!(part1(&disk), 1928);
 
         Ok(())
@@ -194,8 +213,22 @@ mod tests {
 
     #[test]
     fn test_part2() -> Result<(), String> {
-        assert_eq!(part2(&get_line()), 2858);
+        assert_eq!(part2(&get_digits()), 2858);
 
         Ok(())
     }
+
+    #[test]
+    fn test_parse_error() {
+        let lines = vec!["2333".to_string(), "13x3".to_string()];
+        let error = parse_digits(&lines).unwrap_err();
+        assert!(matches!(
+            error,
+            Error::Parse {
+                line: 2,
+                column: Some(3),
+                ..
+            }
+        ));
+    }
 }
@@ -1,60 +1,74 @@
-use crate::years::AdventDay;
+use crate::error::{Error, Result};
+use crate::library::parse::parse_number;
+use crate::library::text::InputText;
+use crate::library::validate::Check;
+use crate::years::{AdventDay, Context, Example, Solution, Solver};
 
 pub struct Day1 {}
 
 impl AdventDay for Day1 {
-    fn solve(&self) {
-        let lines = self.get_input();
-        println!("Part1 solution: {}", part1(&lines));
-        println!("Part2 solution: {}", part2(&lines));
+    fn solve_input(&self, input: &InputText, ctx: &Context) -> Result<Solution> {
+        Solver::try_parse(ctx, || parse_rotations(input))?
+            .part1(|rotations| part1(rotations))
+            .part2(|rotations| part2(rotations))
     }
 
     fn get_input_path(&self) -> &str {
         "Inputs/2025/day1.txt"
     }
+
+    fn examples(&self) -> &'static [Example] {
+        EXAMPLES
+    }
+
+    fn input_checks(&self) -> &'static [Check] {
+        &[Check::Lines(r"[LR]\d+")]
+    }
 }
 
-#[derive(Debug)]
+#[derive(Debug, Clone, Copy)]
 enum Direction {
     Right,
     Left,
 }
 
-#[derive(Debug)]
+#[derive(Debug, Clone, Copy)]
 struct Rotation {
     dir: Direction,
     distance: i32,
 }
 
-fn parse_rotations(lines: &[String]) -> Vec<Rotation> {
-    lines
-        .iter()
-        .map(|s| {
-            let mut chars = s.chars();
-            let c = chars.next().unwrap();
-
-            let i = chars.as_str().parse().unwrap();
-
-            match c {
-                'R' => Rotation {
-                    dir: Direction::Right,
-                    distance: i,
-                },
-                'L' => Rotation {
-                    dir: Direction::Left,
-                    distance: i,
-                },
-                _ => panic!("Got unexpected char '{c}'"),
-            }
-        })
+fn parse_rotation(line: &str, line_idx: usize) -> Result<Rotation> {
+    let mut chars = line.chars();
+    let dir = match chars.next() {
+        Some('R') => Direction::Right,
+        Some('L') => Direction::Left,
+        Some(c) => {
+            return Err(Error::parse_at(
+                line_idx,
+                0,
+                format!("expected 'L' or 'R', got '{c}'"),
+            ));
+        }
+        None => return Err(Error::parse(line_idx, "expected a rotation")),
+    };
+    let distance = parse_number(line, chars.as_str(), line_idx)?;
+
+    Ok(Rotation { dir, distance })
+}
+
+fn parse_rotations(input: &InputText) -> Result<Vec<Rotation>> {
+    input
+        .lines()
+        .enumerate()
+        .map(|(line_idx, line)| parse_rotation(line, line_idx))
         .collect()
 }
 
-fn part1(lines: &[String]) -> u32 {
+fn part1(rotations: &[Rotation]) -> u32 {
     let mut num_pointing_towards_0 = 0;
     let mut curr_dial_pos = 50;
-    let rotations = parse_rotations(lines);
-    for Rotation { dir, distance } in rotations {
+    for &Rotation { dir, distance } in rotations {
         match dir {
             Direction::Right => curr_dial_pos = (curr_dial_pos + distance).rem_euclid(100),
             Direction::Left => curr_dial_pos = (curr_dial_pos - distance).rem_euclid(100),
@@ -67,11 +81,10 @@ fn part1(lines: &[String]) -> u32 {
     num_pointing_towards_0
 }
 
-fn part2(lines: &[String]) -> u32 {
+fn part2(rotations: &[Rotation]) -> u32 {
     let mut num_pointing_towards_0 = 0;
     let mut curr_dial_pos = 50;
-    let rotations = parse_rotations(lines);
-    for Rotation { dir, distance } in rotations {
+    for &Rotation { dir, distance } in rotations {
         let starting_at_zero = curr_dial_pos == 0;
         if distance > 100 {
             num_pointing_towards_0 += distance / 100;
@@ -102,13 +115,7 @@ fn part2(lines: &[String]) -> u32 {
     num_pointing_towards_0 as u32
 }
 
-#[cfg(test)]
-mod tests {
-    use super::*;
-    use crate::library::*;
-
-    fn get_lines() -> Vec<String> {
-        let input = r#"L68
+const EXAMPLE: &str = r#"L68
 L30
 R48
 L5
@@ -119,66 +126,108 @@ L99
 R14
 L82"#;
 
-        parse_lines(&input)
+const EXAMPLES: &[Example] = &[Example {
+    name: "example",
+    input: EXAMPLE,
+    part1: Some("3"),
+    part2: Some("6"),
+    params: &[],
+}];
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn get_input() -> Vec<Rotation> {
+        parse_rotations(&InputText::from(EXAMPLE)).unwrap()
     }
 
-    fn get_loop_case() -> Vec<String> {
+    fn get_loop_case() -> Vec<Rotation> {
         let input = r#"R1000
 L50"#;
 
-        parse_lines(&input)
+        parse_rotations(&InputText::from(input)).unwrap()
     }
 
-    fn edge_case_right() -> Vec<String> {
+    fn edge_case_right() -> Vec<Rotation> {
         let input = r#"R150
 L300
 R101
 L1"#;
-        parse_lines(&input)
+        parse_rotations(&InputText::from(input)).unwrap()
     }
 
-    fn edge_case_left() -> Vec<String> {
+    fn edge_case_left() -> Vec<Rotation> {
         let input = r#"L250"#;
-        parse_lines(&input)
+        parse_rotations(&InputText::from(input)).unwrap()
     }
 
     #[test]
     fn test_part1() -> Result<(), String> {
-        let lines = get_lines();
-        assert_eq!(part1(&lines), 3);
+        let input = get_input();
+        assert_eq!(part1(&input), 3);
 
         Ok(())
     }
 
     #[test]
     fn test_part2() -> Result<(), String> {
-        let lines = get_lines();
-        assert_eq!(part2(&lines), 6);
+        let input = get_input();
+        assert_eq!(part2(&input), 6);
 
         Ok(())
     }
 
     #[test]
     fn test_part2_loop_case() -> Result<(), String> {
-        let lines = get_loop_case();
-        assert_eq!(part2(&lines), 11);
+        let input = get_loop_case();
+        assert_eq!(part2(&input), 11);
 
         Ok(())
     }
 
     #[test]
     fn test_part2_edge_case_left() -> Result<(), String> {
-        let lines = edge_case_left();
-        assert_eq!(part2(&lines), 3);
+        let input = edge_case_left();
+        assert_eq!(part2(&input), 3);
 
         Ok(())
     }
 
     #[test]
     fn test_part2_edge_case_right() -> Result<(), String> {
-        let lines = edge_case_right();
-        assert_eq!(part2(&lines), 7);
+        let input = edge_case_right();
+        assert_eq!(part2(&input), 7);
 
         Ok(())
     }
+
+    #[test]
+    fn test_parse_error() {
+        let error = parse_rotations(&InputText::from("L68\nU30")).unwrap_err();
+        assert!(
+            matches!(
+                error,
+                Error::Parse {
+                    line: 2,
+                    column: Some(1),
+                    ..
+                }
+            ),
+            "{error}"
+        );
+
+        let error = parse_rotations(&InputText::from("R4x")).unwrap_err();
+        assert!(
+            matches!(
+                error,
+                Error::Parse {
+                    line: 1,
+                    column: Some(2),
+                    ..
+                }
+            ),
+            "{error}"
+        );
+    }
 }
@@ -1,17 +1,28 @@
-use crate::years::AdventDay;
+use crate::error::{Error, Result, offset_in};
+use crate::library::parse::parse_number;
+use crate::library::validate::Check;
+use crate::years::{AdventDay, Context, Example, Solution, Solver};
 
 pub struct Day2 {}
 
 impl AdventDay for Day2 {
-    fn solve(&self) {
-        let lines = self.get_input();
-        println!("Part1 solution: {}", part1(&lines));
-        println!("Part2 solution: {}", part2(&lines));
+    fn solve(&self, lines: &[String], ctx: &Context) -> Result<Solution> {
+        Solver::try_parse(ctx, || parse_ranges(lines))?
+            .part1(|ranges| part1(ranges))
+            .part2(|ranges| part2(ranges))
     }
 
     fn get_input_path(&self) -> &str {
         "Inputs/2025/day2.txt"
     }
+
+    fn examples(&self) -> &'static [Example] {
+        EXAMPLES
+    }
+
+    fn input_checks(&self) -> &'static [Check] {
+        &[Check::Lines(r"\d+-\d+(,\d+-\d+)*,?")]
+    }
 }
 
 struct Range {
@@ -19,29 +30,25 @@ struct Range {
     end: u64,
 }
 
-fn parse_ranges(lines: &[String]) -> Vec<Range> {
+fn parse_ranges(lines: &[String]) -> Result<Vec<Range>> {
     let mut ranges = Vec::new();
-    for line in lines {
-        for range_str in line.split(',') {
-            let mut range_parts = range_str.split('-');
-
-            let start = range_parts.next();
-            let end = range_parts.next();
-
-            if start.is_none() || end.is_none() {
-                panic!("Could not parse range out of {}", range_str);
-            }
-
-            if let Ok(start) = start.unwrap().parse()
-                && let Ok(end) = end.unwrap().parse()
-            {
-                ranges.push(Range { start, end });
-                continue;
-            }
-            panic!("Could not parse range from {range_str}");
+    for (line_idx, line) in lines.iter().enumerate() {
+        for range_str in line.split(',').filter(|range_str| !range_str.is_empty()) {
+            let Some((start, end)) = range_str.split_once('-') else {
+                return Err(Error::parse_at(
+                    line_idx,
+                    offset_in(line, range_str),
+                    format!("expected '<start>-<end>', got '{range_str}'"),
+                ));
+            };
+
+            ranges.push(Range {
+                start: parse_number(line, start, line_idx)?,
+                end: parse_number(line, end, line_idx)?,
+            });
         }
     }
-    ranges
+    Ok(ranges)
 }
 
 fn is_mirrored_number(id: &u64) -> bool {
@@ -100,8 +107,7 @@ where
         .collect()
 }
 
-fn part1(lines: &[String]) -> u64 {
-    let ranges = parse_ranges(lines);
+fn part1(ranges: &[Range]) -> u64 {
     ranges
         .iter()
         .map(|range| {
@@ -112,8 +118,7 @@ fn part1(lines: &[String]) -> u64 {
         .sum()
 }
 
-fn part2(lines: &[String]) -> u64 {
-    let ranges = parse_ranges(lines);
+fn part2(ranges: &[Range]) -> u64 {
     ranges
         .iter()
         .map(|range| {
@@ -124,30 +129,61 @@ fn part2(lines: &[String]) -> u64 {
         .sum()
 }
 
+const EXAMPLE: &str = r#"11-22,95-115,998-1012,1188511880-1188511890,222220-222224,1698522-1698528,446443-446449,38593856-38593862,565653-565659,824824821-824824827,2121212118-2121212124"#;
+
+const EXAMPLES: &[Example] = &[Example {
+    name: "example",
+    input: EXAMPLE,
+    part1: Some("1227775554"),
+    part2: Some("4174379265"),
+    params: &[],
+}];
+
 #[cfg(test)]
 mod tests {
     use super::*;
     use crate::library::*;
 
-    fn get_lines() -> Vec<String> {
-        let input = r#"11-22,95-115,998-1012,1188511880-1188511890,222220-222224,1698522-1698528,446443-446449,38593856-38593862,565653-565659,824824821-824824827,2121212118-2121212124"#;
-
-        parse_lines(&input)
+    fn get_ranges() -> Vec<Range> {
+        parse_ranges(&parse_lines(EXAMPLE)).unwrap()
     }
 
     #[test]
     fn test_part1() -> Result<(), String> {
-        let lines = get_lines();
-        assert_eq!(part1(&lines), 1227775554);
+        let ranges = get_ranges();
+        assert_eq!(part1(&ranges), 1227775554);
 
         Ok(())
     }
 
     #[test]
     fn test_part2() -> Result<(), String> {
-        let lines = get_lines();
-        assert_eq!(part2(&lines), 4174379265);
+        let ranges = get_ranges();
+        assert_eq!(part2(&ranges), 4174379265);
 
         Ok(())
     }
+
+    #[test]
+    fn test_parse_error() {
+        let error = parse_ranges(&parse_lines("11-22,95-11x5,")).err().unwrap();
+        assert!(matches!(
+            error,
+            Error::Parse {
+                line: 1,
+                column: Some(10),
+                ..
+            }
+        ));
+
+        let error = parse_ranges(&parse_lines("11-22,95")).err().unwrap();
+        assert!(matches!(
+            error,
+            Error::Parse {
+                line: 1,
+                column: Some(7),
+                ..
+            }
+        ));
+    }
 }
@@ -1,29 +1,44 @@
-use crate::years::AdventDay;
+use crate::error::{Error, Result};
+use crate::library::validate::Check;
+use crate::years::{AdventDay, Context, Example, Solution, Solver};
 use std::collections::HashMap;
 
 pub struct Day3 {}
 
 impl AdventDay for Day3 {
-    fn solve(&self) {
-        let lines = self.get_input();
-        println!("Part1 solution: {}", part1(&lines));
-        println!("Part2 solution: {}", part2(&lines)); // 173848577117259 too low
+    fn solve(&self, lines: &[String], ctx: &Context) -> Result<Solution> {
+        Solver::try_parse(ctx, || parse_banks(lines))?
+            .part1(|banks| part1(banks))
+            .part2(|banks| part2(banks))
     }
 
     fn get_input_path(&self) -> &str {
         "Inputs/2025/day3.txt"
     }
+
+    fn examples(&self) -> &'static [Example] {
+        EXAMPLES
+    }
+
+    fn input_checks(&self) -> &'static [Check] {
+        &[Check::Lines(r"\d+"), Check::Grid]
+    }
 }
 
 type Bank = Vec<u32>;
 
-fn parse_banks(lines: &[String]) -> Vec<Bank> {
+fn parse_banks(lines: &[String]) -> Result<Vec<Bank>> {
     lines
         .iter()
-        .map(|line| {
-            line.chars()
-                .filter_map(|c| c.to_digit(10))
-                .collect::<Bank>()
+        .enumerate()
+        .map(|(line_idx, line)| {
+            line.char_indices()
+                .map(|(col_idx, c)| {
+                    c.to_digit(10).ok_or_else(|| {
+                        Error::parse_at(line_idx, col_idx, format!("expected a digit, got '{c}'"))
+                    })
+                })
+                .collect::<Result<Bank>>()
         })
         .collect()
 }
@@ -38,8 +53,7 @@ fn get_largest_joltage(bank: &Bank) -> u32 {
     largest_joltage
 }
 
-fn part1(lines: &[String]) -> u32 {
-    let banks = parse_banks(lines);
+fn part1(banks: &[Bank]) -> u32 {
     banks.iter().map(get_largest_joltage).sum()
 }
 
@@ -74,8 +88,7 @@ fn get_largest_joltage2<'a>(
     result
 }
 
-fn part2(lines: &[String]) -> u64 {
-    let banks = parse_banks(lines);
+fn part2(banks: &[Bank]) -> u64 {
     banks
         .iter()
         .map(|bank| {
@@ -85,33 +98,54 @@ fn part2(lines: &[String]) -> u64 {
         .sum()
 }
 
+const EXAMPLE: &str = r#"987654321111111
+811111111111119
+234234234234278
+818181911112111"#;
+
+const EXAMPLES: &[Example] = &[Example {
+    name: "example",
+    input: EXAMPLE,
+    part1: Some("357"),
+    part2: Some("3121910778619"),
+    params: &[],
+}];
+
 #[cfg(test)]
 mod tests {
     use super::*;
     use crate::library::*;
 
-    fn get_lines() -> Vec<String> {
-        let input = r#"987654321111111
-811111111111119
-234234234234278
-818181911112111"#;
-
-        parse_lines(&input)
+    fn get_banks() -> Vec<Bank> {
+        parse_banks(&parse_lines(EXAMPLE)).unwrap()
     }
 
     #[test]
     fn test_part1() -> Result<(), String> {
-        let lines = get_lines();
-        assert_eq!(part1(&lines), 357);
+        let banks = get_banks();
+        assert_eq!(part1(&banks), 357);
 
         Ok(())
     }
 
     #[test]
     fn test_part2() -> Result<(), String> {
-        let lines = get_lines();
-        assert_eq!(part2(&lines), 3121910778619);
+        let banks = get_banks();
+        assert_eq!(part2(&banks), 3121910778619);
 
         Ok(())
     }
+
+    #[test]
+    fn test_parse_error() {
+        let error = parse_banks(&parse_lines("987654321\n8119 1")).unwrap_err();
+        assert!(matches!(
+            error,
+            Error::Parse {
+                line: 2,
+                column: Some(5),
+                ..
+            }
+        ));
+    }
 }
@@ -1,22 +1,32 @@
-use crate::years::AdventDay;
+use crate::error::{Error, Result};
+use crate::library::validate::Check;
+use crate::years::{AdventDay, Context, Example, Solution, Solver};
 
 use super::{Map, Pos};
 
 pub struct Day4 {}
 
 impl AdventDay for Day4 {
-    fn solve(&self) {
-        let lines = self.get_input();
-        println!("Part1 solution: {}", part1(&lines));
-        println!("Part2 solution: {}", part2(&lines));
+    fn solve(&self, lines: &[String], ctx: &Context) -> Result<Solution> {
+        Solver::try_parse(ctx, || parse_map(lines))?
+            .part1(part1)
+            .part2(part2)
     }
 
     fn get_input_path(&self) -> &str {
         "Inputs/2025/day4.txt"
     }
+
+    fn examples(&self) -> &'static [Example] {
+        EXAMPLES
+    }
+
+    fn input_checks(&self) -> &'static [Check] {
+        &[Check::Chars(".@"), Check::Grid]
+    }
 }
 
-#[derive(Debug, PartialEq)]
+#[derive(Debug, Clone, PartialEq)]
 enum Space {
     Empty,
     PaperRoll,
@@ -56,21 +66,24 @@ fn forklift_accessable_papers(map: &Map<Space>) -> Vec<Pos> {
     accessable_paper_positions
 }
 
-fn parse_map(lines: &[String]) -> Map<Space> {
-    Map::new(lines, |c, _pos| match c {
-        '.' => Space::Empty,
-        '@' => Space::PaperRoll,
-        _ => panic!("Found unexpected char '{c}'"),
+fn parse_map(lines: &[String]) -> Result<Map<Space>> {
+    Map::try_new(lines, |c, pos| match c {
+        '.' => Ok(Space::Empty),
+        '@' => Ok(Space::PaperRoll),
+        _ => Err(Error::parse_at(
+            pos.y as usize,
+            pos.x as usize,
+            format!("expected '.' or '@', got '{c}'"),
+        )),
     })
 }
 
-fn part1(lines: &[String]) -> usize {
-    let map = parse_map(lines);
-    forklift_accessable_papers(&map).len()
+fn part1(map: &Map<Space>) -> usize {
+    forklift_accessable_papers(map).len()
 }
 
-fn part2(lines: &[String]) -> usize {
-    let mut map = parse_map(lines);
+fn part2(map: &Map<Space>) -> usize {
+    let mut map = map.clone();
     let mut removed_paper_rolls = 0;
     loop {
         let accessable_paper_rolls = forklift_accessable_papers(&map);
@@ -87,13 +100,7 @@ fn part2(lines: &[String]) -> usize {
     removed_paper_rolls
 }
 
-#[cfg(test)]
-mod tests {
-    use super::*;
-    use crate::library::*;
-
-    fn get_lines() -> Vec<String> {
-        let input = r#"..@@.@@@@.
+const EXAMPLE: &str = r#"..@@.@@@@.
 @@@.@.@.@@
 @@@@@.@.@@
 @.@@@@..@.
@@ -104,22 +111,49 @@ mod tests {
 .@@@@@@@@.
 @.@.@@@.@."#;
 
-        parse_lines(&input)
+const EXAMPLES: &[Example] = &[Example {
+    name: "example",
+    input: EXAMPLE,
+    part1: Some("13"),
+    part2: Some("43"),
+    params: &[],
+}];
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::library::*;
+
+    fn get_map() -> Map<Space> {
+        parse_map(&parse_lines(EXAMPLE)).unwrap()
     }
 
     #[test]
     fn test_part1() -> Result<(), String> {
-        let lines = get_lines();
-        assert_eq!(part1(&lines), 13);
+        let map = get_map();
+        assert_eq!(part1(&map), 13);
 
         Ok(())
     }
 
     #[test]
     fn test_part2() -> Result<(), String> {
-        let lines = get_lines();
-        assert_eq!(part2(&lines), 43);
+        let map = get_map();
+        assert_eq!(part2(&map), 43);
 
         Ok(())
     }
+
+    #[test]
+    fn test_parse_error() {
+        let error = parse_map(&parse_lines("..@@\n@@#.")).err().unwrap();
+        assert!(matches!(
+            error,
+            Error::Parse {
+                line: 2,
+                column: Some(3),
+                ..
+            }
+        ));
+    }
 }
@@ -1,47 +1,58 @@
-use crate::years::AdventDay;
+use crate::error::{Error, Result};
+use crate::library::parse::{parse_number, split_sections};
+use crate::library::text::InputText;
+use crate::library::validate::Check;
+use crate::years::{AdventDay, Context, Example, Solution, Solver};
 use std::ops::RangeInclusive;
 
 pub struct Day5 {}
 
 impl AdventDay for Day5 {
-    fn solve(&self) {
-        let lines = self.get_input();
-        println!("Part1 solution: {}", part1(&lines));
-        println!("Part2 solution: {}", part2(&lines));
+    fn solve_input(&self, input: &InputText, ctx: &Context) -> Result<Solution> {
+        Solver::try_parse(ctx, || parse_input(input))?
+            .part1(|(ranges, ids)| part1(ranges, ids))
+            .part2(|(ranges, _)| part2(ranges))
     }
 
     fn get_input_path(&self) -> &str {
         "Inputs/2025/day5.txt"
     }
+
+    fn examples(&self) -> &'static [Example] {
+        EXAMPLES
+    }
+
+    fn input_checks(&self) -> &'static [Check] {
+        &[Check::Lines(r"\d+-\d+|\d+")]
+    }
 }
 
 type IngredientId = u64;
 type FreshIngredientIds = RangeInclusive<IngredientId>;
 
-fn parse_input(lines: &[String]) -> (Vec<FreshIngredientIds>, Vec<IngredientId>) {
-    let mut ingredient_ids = Vec::new();
-    let mut fresh_ingredient_ids = Vec::new();
+fn parse_range(line: &str, idx: usize) -> Result<FreshIngredientIds> {
+    let (start, end) = line
+        .split_once('-')
+        .ok_or_else(|| Error::parse(idx, "expected '<start>-<end>'"))?;
+    Ok(FreshIngredientIds::new(
+        parse_number(line, start, idx)?,
+        parse_number(line, end, idx)?,
+    ))
+}
 
-    let mut fresh_ingredients_parsed = false;
-    for line in lines {
-        if line.is_empty() {
-            fresh_ingredients_parsed = true;
-            continue;
-        }
+fn parse_input(input: &InputText) -> Result<(Vec<FreshIngredientIds>, Vec<IngredientId>)> {
+    let [ranges, ids] = split_sections(input)?;
 
-        if fresh_ingredients_parsed {
-            if let Ok(ingredient_id) = line.parse() {
-                ingredient_ids.push(ingredient_id)
-            }
-        } else if let Some((start_str, end_str)) = line.split_once('-')
-            && let Ok(start) = start_str.parse()
-            && let Ok(end) = end_str.parse()
-        {
-            fresh_ingredient_ids.push(FreshIngredientIds::new(start, end));
-        }
-    }
+    let fresh_ingredient_ids = ranges
+        .indexed()
+        .map(|(idx, line)| parse_range(line, idx))
+        .collect::<Result<_>>()?;
+    let ingredient_ids = ids
+        .indexed()
+        .map(|(idx, line)| parse_number(line, line, idx))
+        .collect::<Result<_>>()?;
 
-    (fresh_ingredient_ids, ingredient_ids)
+    Ok((fresh_ingredient_ids, ingredient_ids))
 }
 
 fn find_fresh_ingredient_ids(
@@ -60,9 +71,8 @@ fn find_fresh_ingredient_ids(
     fresh_ones
 }
 
-fn part1(lines: &[String]) -> usize {
-    let (fresh_ingredient_ids, ingredient_ids) = parse_input(lines);
-    find_fresh_ingredient_ids(&fresh_ingredient_ids, &ingredient_ids)
+fn part1(fresh_ingredient_ids: &[FreshIngredientIds], ingredient_ids: &[IngredientId]) -> usize {
+    find_fresh_ingredient_ids(fresh_ingredient_ids, ingredient_ids)
         .iter()
         .len()
 }
@@ -108,9 +118,8 @@ fn merge_ranges(ranges: &[FreshIngredientIds]) -> Vec<FreshIngredientIds> {
     new_ranges
 }
 
-fn part2(lines: &[String]) -> u64 {
-    let (mut fresh_ingredient_ids, _ingredient_ids) = parse_input(lines);
-    fresh_ingredient_ids = merge_ranges(&fresh_ingredient_ids);
+fn part2(fresh_ingredient_ids: &[FreshIngredientIds]) -> u64 {
+    let fresh_ingredient_ids = merge_ranges(fresh_ingredient_ids);
 
     let mut fresh_ids = 0;
     for range in fresh_ingredient_ids {
@@ -119,13 +128,7 @@ fn part2(lines: &[String]) -> u64 {
     fresh_ids
 }
 
-#[cfg(test)]
-mod tests {
-    use super::*;
-    use crate::library::*;
-
-    fn get_lines() -> Vec<String> {
-        let input = r#"3-5
+const EXAMPLE: &str = r#"3-5
 10-14
 16-20
 12-18
@@ -137,21 +140,46 @@ mod tests {
 17
 32"#;
 
-        parse_lines(&input)
+const EXAMPLES: &[Example] = &[Example {
+    name: "example",
+    input: EXAMPLE,
+    part1: Some("3"),
+    part2: Some("14"),
+    params: &[],
+}];
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn get_input() -> (Vec<FreshIngredientIds>, Vec<IngredientId>) {
+        parse_input(&InputText::from(EXAMPLE)).unwrap()
+    }
+
+    #[test]
+    fn test_parse_error() {
+        let error = parse_input(&InputText::from("3-5\n10:14\n\n1")).unwrap_err();
+        assert_eq!(error.to_string(), "line 2: expected '<start>-<end>'");
+
+        let error = parse_input(&InputText::from("3-5\n\n1\n5a")).unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "line 4, column 1: expected a number, got '5a'"
+        );
     }
 
     #[test]
     fn test_part1() -> Result<(), String> {
-        let lines = get_lines();
-        assert_eq!(part1(&lines), 3);
+        let (ranges, ids) = get_input();
+        assert_eq!(part1(&ranges, &ids), 3);
 
         Ok(())
     }
 
     #[test]
     fn test_part2() -> Result<(), String> {
-        let lines = get_lines();
-        assert_eq!(part2(&lines), 14);
+        let (ranges, _) = get_input();
+        assert_eq!(part2(&ranges), 14);
 
         Ok(())
     }
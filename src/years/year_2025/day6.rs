@@ -1,17 +1,28 @@
-use crate::years::AdventDay;
+use crate::error::{Error, Result, offset_in};
+use crate::library::parse::parse_number;
+use crate::library::validate::Check;
+use crate::years::{AdventDay, Context, Example, Solution, Solver};
 
 pub struct Day6 {}
 
 impl AdventDay for Day6 {
-    fn solve(&self) {
-        let lines = self.get_input();
-        println!("Part1 solution: {}", part1(&lines));
-        println!("Part2 solution: {}", part2(&lines));
+    fn solve(&self, lines: &[String], ctx: &Context) -> Result<Solution> {
+        Solver::try_parse(ctx, || parse_input(lines))?
+            .part1(|(homework, _)| solve_homework(homework))
+            .part2(|(_, transposed_homework)| solve_homework(transposed_homework))
     }
 
     fn get_input_path(&self) -> &str {
         "Inputs/2025/day6.txt"
     }
+
+    fn examples(&self) -> &'static [Example] {
+        EXAMPLES
+    }
+
+    fn input_checks(&self) -> &'static [Check] {
+        &[Check::Lines(r"[\d *+]+")]
+    }
 }
 
 #[derive(Debug, Clone, Copy, PartialEq)]
@@ -26,7 +37,7 @@ struct Problem {
     op: Operation,
 }
 
-fn parse_strings(lines: &[String]) -> (Vec<Vec<u64>>, Vec<Operation>) {
+fn parse_strings(lines: &[String]) -> Result<(Vec<Vec<u64>>, Vec<Operation>)> {
     let num_elements = if let Some(line) = lines.first() {
         line.split_ascii_whitespace().count()
     } else {
@@ -35,22 +46,23 @@ fn parse_strings(lines: &[String]) -> (Vec<Vec<u64>>, Vec<Operation>) {
 
     let mut numbers = vec![Vec::new(); num_elements];
     let mut operations = vec![Operation::Multiply; num_elements];
-    for line in lines {
-        line.split_ascii_whitespace()
-            .enumerate()
-            .for_each(|(idx, num)| {
-                if let Ok(num) = num.parse() {
-                    numbers[idx].push(num);
-                } else {
-                    match num {
-                        "*" => operations[idx] = Operation::Multiply,
-                        "+" => operations[idx] = Operation::Add,
-                        _ => panic!("Couldn't parse '{num}' to either a number or operation"),
-                    }
-                }
-            });
+    for (line_idx, line) in lines.iter().enumerate() {
+        for (idx, num) in line.split_ascii_whitespace().enumerate() {
+            if idx >= num_elements {
+                return Err(Error::parse_at(
+                    line_idx,
+                    offset_in(line, num),
+                    format!("expected {num_elements} problems like the first line"),
+                ));
+            }
+            match num {
+                "*" => operations[idx] = Operation::Multiply,
+                "+" => operations[idx] = Operation::Add,
+                _ => numbers[idx].push(parse_number(line, num, line_idx)?),
+            }
+        }
     }
-    (numbers, operations)
+    Ok((numbers, operations))
 }
 
 fn zip_problems(numbers: &[Vec<u64>], operations: &[Operation]) -> Vec<Problem> {
@@ -64,9 +76,9 @@ fn zip_problems(numbers: &[Vec<u64>], operations: &[Operation]) -> Vec<Problem>
         .collect()
 }
 
-fn parse_homework(lines: &[String]) -> Vec<Problem> {
-    let (numbers, operations) = parse_strings(lines);
-    zip_problems(&numbers, &operations)
+fn parse_homework(lines: &[String]) -> Result<Vec<Problem>> {
+    let (numbers, operations) = parse_strings(lines)?;
+    Ok(zip_problems(&numbers, &operations))
 }
 
 fn solve_homework(homework: &[Problem]) -> u64 {
@@ -79,16 +91,12 @@ fn solve_homework(homework: &[Problem]) -> u64 {
         .sum()
 }
 
-fn part1(lines: &[String]) -> u64 {
-    let homework = parse_homework(lines);
-    solve_homework(&homework)
-}
-
-fn parse_transposed_howework(lines: &[String]) -> Vec<Problem> {
-    let mut transposed = vec![String::new(); lines[0].len() + 1];
+fn parse_transposed_howework(lines: &[String]) -> Result<Vec<Problem>> {
+    let width = lines.iter().map(|line| line.len()).max().unwrap_or(0);
+    let mut transposed = vec![String::new(); width];
     let mut operations = Vec::new();
     for line in lines {
-        for (idx, char) in line.chars().enumerate() {
+        for (idx, char) in line.char_indices() {
             match char {
                 '*' => operations.push(Operation::Multiply),
                 '+' => operations.push(Operation::Add),
@@ -97,61 +105,91 @@ fn parse_transposed_howework(lines: &[String]) -> Vec<Problem> {
         }
     }
 
-    let mut numbers_str = vec![String::new(); 1];
-    let mut idx = 0;
-    for str in transposed {
-        if str.trim().is_empty() {
-            idx += 1;
-            numbers_str.push(String::new());
+    // An empty column separates two problems
+    let mut numbers = vec![Vec::new()];
+    for (col_idx, column) in transposed.iter().enumerate() {
+        let column = column.trim();
+        if column.is_empty() {
+            numbers.push(Vec::new());
+            continue;
+        }
+        let number = column.parse().map_err(|_| {
+            Error::parse_at(
+                0,
+                col_idx,
+                format!("expected the column to hold a number, got '{column}'"),
+            )
+        })?;
+        if let Some(problem_numbers) = numbers.last_mut() {
+            problem_numbers.push(number);
         }
-        numbers_str[idx].push(' '); // Making sure there is atleast one space between numbers
-        numbers_str[idx].push_str(&str);
     }
 
-    let numbers: Vec<Vec<u64>> = numbers_str
-        .iter()
-        .map(|str| {
-            str.split_ascii_whitespace()
-                .map(|n| n.parse().unwrap_or(0))
-                .collect()
-        })
-        .collect();
-
-    zip_problems(&numbers, &operations)
+    Ok(zip_problems(&numbers, &operations))
 }
 
-fn part2(lines: &[String]) -> u64 {
-    let homework = parse_transposed_howework(lines);
-    solve_homework(&homework)
+fn parse_input(lines: &[String]) -> Result<(Vec<Problem>, Vec<Problem>)> {
+    Ok((parse_homework(lines)?, parse_transposed_howework(lines)?))
 }
 
+const EXAMPLE: &str = r#"123 328  51 64
+ 45 64  387 23
+  6 98  215 314
+*   +   *   +"#;
+
+const EXAMPLES: &[Example] = &[Example {
+    name: "example",
+    input: EXAMPLE,
+    part1: Some("4277556"),
+    part2: Some("3263827"),
+    params: &[],
+}];
+
 #[cfg(test)]
 mod tests {
     use super::*;
     use crate::library::*;
 
     fn get_lines() -> Vec<String> {
-        let input = r#"123 328  51 64
- 45 64  387 23
-  6 98  215 314
-*   +   *   +"#;
-
-        parse_lines(&input)
+        parse_lines(EXAMPLE)
     }
 
     #[test]
     fn test_part1() -> Result<(), String> {
-        let lines = get_lines();
-        assert_eq!(part1(&lines), 4277556);
+        let homework = parse_homework(&get_lines()).unwrap();
+        assert_eq!(solve_homework(&homework), 4277556);
 
         Ok(())
     }
 
     #[test]
     fn test_part2() -> Result<(), String> {
-        let lines = get_lines();
-        assert_eq!(part2(&lines), 3263827);
+        let homework = parse_transposed_howework(&get_lines()).unwrap();
+        assert_eq!(solve_homework(&homework), 3263827);
 
         Ok(())
     }
+
+    #[test]
+    fn test_parse_error() {
+        let error = parse_input(&parse_lines("123 328\n 45 6x4\n*   +")).unwrap_err();
+        assert!(matches!(
+            error,
+            Error::Parse {
+                line: 2,
+                column: Some(5),
+                ..
+            }
+        ));
+
+        let error = parse_input(&parse_lines("123 328\n 45 64 1\n*   +")).unwrap_err();
+        assert!(matches!(
+            error,
+            Error::Parse {
+                line: 2,
+                column: Some(8),
+                ..
+            }
+        ));
+    }
 }
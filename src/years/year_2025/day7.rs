@@ -1,19 +1,29 @@
-use crate::years::AdventDay;
+use crate::error::Result;
+use crate::library::validate::Check;
+use crate::years::{AdventDay, Context, Example, Solution, Solver};
 
 use std::collections::HashMap;
 
 pub struct Day7 {}
 
 impl AdventDay for Day7 {
-    fn solve(&self) {
-        let lines = self.get_input();
-        println!("Part1 solution: {}", part1(&lines));
-        println!("Part2 solution: {}", part2(&lines));
+    fn solve(&self, lines: &[String], ctx: &Context) -> Result<Solution> {
+        Solver::new(ctx, lines)
+            .part1(|lines| part1(lines))
+            .part2(|lines| part2(lines))
     }
 
     fn get_input_path(&self) -> &str {
         "Inputs/2025/day7.txt"
     }
+
+    fn examples(&self) -> &'static [Example] {
+        EXAMPLES
+    }
+
+    fn input_checks(&self) -> &'static [Check] {
+        &[Check::Chars(".S^"), Check::Grid]
+    }
 }
 
 fn find_start_pos(lines: &[String]) -> Option<usize> {
@@ -92,13 +102,7 @@ fn part2(lines: &[String]) -> usize {
     find_timeline_splits(lines, 0, start_beam_position, &mut memoization)
 }
 
-#[cfg(test)]
-mod tests {
-    use super::*;
-    use crate::library::*;
-
-    fn get_lines() -> Vec<String> {
-        let input = r#".......S.......
+const EXAMPLE: &str = r#".......S.......
 ...............
 .......^.......
 ...............
@@ -115,7 +119,21 @@ mod tests {
 .^.^.^.^.^...^.
 ..............."#;
 
-        parse_lines(&input)
+const EXAMPLES: &[Example] = &[Example {
+    name: "example",
+    input: EXAMPLE,
+    part1: Some("21"),
+    part2: Some("40"),
+    params: &[],
+}];
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::library::*;
+
+    fn get_lines() -> Vec<String> {
+        parse_lines(EXAMPLE)
     }
 
     #[test]
@@ -1,4 +1,6 @@
-use crate::years::AdventDay;
+use crate::error::{Error, Result};
+use crate::library::validate::Check;
+use crate::years::{AdventDay, Context, Example, Param, Solution, Solver};
 
 use std::collections::HashSet;
 
@@ -7,15 +9,31 @@ use super::Pos3d;
 pub struct Day8 {}
 
 impl AdventDay for Day8 {
-    fn solve(&self) {
-        let lines = self.get_input();
-        println!("Part1 solution: {}", part1(&lines, 1000));
-        println!("Part2 solution: {}", part2(&lines));
+    fn solve(&self, lines: &[String], ctx: &Context) -> Result<Solution> {
+        let connections = ctx.param("connections")?;
+        Solver::try_parse(ctx, || parse_positions(lines))?
+            .part1(|positions| part1(positions, connections))
+            .part2(|positions| part2(positions))
     }
 
     fn get_input_path(&self) -> &str {
         "Inputs/2025/day8.txt"
     }
+
+    fn examples(&self) -> &'static [Example] {
+        EXAMPLES
+    }
+
+    fn input_checks(&self) -> &'static [Check] {
+        &[Check::Lines(r"\d+,\d+,\d+")]
+    }
+
+    fn params(&self) -> &'static [Param] {
+        &[Param {
+            name: "connections",
+            default: "1000",
+        }]
+    }
 }
 
 struct DistancePair {
@@ -24,18 +42,23 @@ struct DistancePair {
     distance: i64,
 }
 
-fn parse_positions(lines: &[String]) -> Vec<Pos3d> {
+fn parse_positions(lines: &[String]) -> Result<Vec<Pos3d>> {
     lines
         .iter()
-        .map(|line| {
+        .enumerate()
+        .map(|(idx, line)| {
             let mut nums = line.split(',');
             if let Some(x) = nums.next().and_then(|s| s.parse().ok())
                 && let Some(y) = nums.next().and_then(|s| s.parse().ok())
                 && let Some(z) = nums.next().and_then(|s| s.parse().ok())
+                && nums.next().is_none()
             {
-                Pos3d { x, y, z }
+                Ok(Pos3d { x, y, z })
             } else {
-                panic!("Could not parse 3d position from '{line}'");
+                Err(Error::parse(
+                    idx,
+                    format!("expected '<x>,<y>,<z>', got '{line}'"),
+                ))
             }
         })
         .collect()
@@ -107,9 +130,8 @@ fn join_junction_boxes(
     circuits
 }
 
-fn part1(lines: &[String], shortest_boxes_to_connect: usize) -> usize {
-    let positions = parse_positions(lines);
-    let distance_pairs = construct_distance_pairs(&positions);
+fn part1(positions: &[Pos3d], shortest_boxes_to_connect: usize) -> usize {
+    let distance_pairs = construct_distance_pairs(positions);
     let mut circuits = join_junction_boxes(&distance_pairs, shortest_boxes_to_connect);
     create_circuits(&mut circuits);
 
@@ -156,21 +178,14 @@ fn find_last_connection(positions: &[Pos3d], distance_pairs: &[DistancePair]) ->
     (0, 0)
 }
 
-fn part2(lines: &[String]) -> usize {
-    let positions = parse_positions(lines);
-    let distance_pairs = construct_distance_pairs(&positions);
-    let (idx1, idx2) = find_last_connection(&positions, &distance_pairs);
+fn part2(positions: &[Pos3d]) -> usize {
+    let distance_pairs = construct_distance_pairs(positions);
+    let (idx1, idx2) = find_last_connection(positions, &distance_pairs);
 
     (positions[idx1].x as i64 * positions[idx2].x as i64) as usize
 }
 
-#[cfg(test)]
-mod tests {
-    use super::*;
-    use crate::library::*;
-
-    fn get_lines() -> Vec<String> {
-        let input = r#"162,817,812
+const EXAMPLE: &str = r#"162,817,812
 57,618,57
 906,360,560
 592,479,940
@@ -191,21 +206,45 @@ mod tests {
 984,92,344
 425,690,689"#;
 
-        parse_lines(&input)
+const EXAMPLES: &[Example] = &[Example {
+    name: "example",
+    input: EXAMPLE,
+    part1: Some("40"),
+    part2: Some("25272"),
+    params: &[("connections", "10")],
+}];
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::library::*;
+
+    fn get_positions() -> Vec<Pos3d> {
+        parse_positions(&parse_lines(EXAMPLE)).unwrap()
+    }
+
+    #[test]
+    fn test_parse_error() {
+        let lines = parse_lines("162,817,812\n57,618");
+        let error = parse_positions(&lines).unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "line 2: expected '<x>,<y>,<z>', got '57,618'"
+        );
     }
 
     #[test]
     fn test_part1() -> Result<(), String> {
-        let lines = get_lines();
-        assert_eq!(part1(&lines, 10), 40);
+        let positions = get_positions();
+        assert_eq!(part1(&positions, 10), 40);
 
         Ok(())
     }
 
     #[test]
     fn test_part2() -> Result<(), String> {
-        let lines = get_lines();
-        assert_eq!(part2(&lines), 25272);
+        let positions = get_positions();
+        assert_eq!(part2(&positions), 25272);
 
         Ok(())
     }